@@ -0,0 +1,137 @@
+//! Times both parts of every day on its bundled data.txt and on the puzzle examples.
+//!
+//! The `main` baseline of the last solver change on the main branch is checked in under
+//! `aoc/benches/baseline`, compare against it by pointing criterion there:
+//!
+//! ```text
+//! CRITERION_HOME=$PWD/aoc/benches/baseline cargo bench -p aoc --bench solvers -- --baseline main
+//! ```
+//!
+//! `--bench solvers` keeps the criterion options away from the library's test harness. Timings
+//! only compare on one machine, so when the checked-in baseline was recorded on another, save
+//! your own on the main branch first with `--save-baseline main` in place of `--baseline main`.
+//! A solver change commits the refreshed baseline along with it. Runs without `CRITERION_HOME`
+//! keep their baselines in `target/criterion`.
+use aoc::{solution, DAYS};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Puzzle examples per day, for part 1 and part 2.
+const EXAMPLES: [(u8, &str, &str); 9] = [
+    (
+        1,
+        "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet",
+        "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen",
+    ),
+    (2, DAY2, DAY2),
+    (3, DAY3, DAY3),
+    (4, DAY4, DAY4),
+    (5, DAY5, DAY5),
+    (6, "Time:      7  15   30\nDistance:  9  40  200", "Time:      7  15   30\nDistance:  9  40  200"),
+    (7, DAY7, DAY7),
+    (
+        8,
+        "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)",
+        "LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\n22A = (22B, XXX)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22B, 22B)\nXXX = (XXX, XXX)",
+    ),
+    (9, DAY9, DAY9),
+];
+
+const DAY2: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+const DAY3: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+const DAY4: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+const DAY5: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+const DAY7: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+const DAY9: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+fn bench_input(c: &mut Criterion, day: u8, name: &str, part1: &str, part2: &str) {
+    let solver = solution(day);
+    //timing an early parse error would pass for a fast solver
+    for (part, result) in [(1, solver.part1(part1)), (2, solver.part2(part2))] {
+        if let Err(err) = result {
+            panic!("day {} part {} fails on its {}: {}", day, part, name, err);
+        }
+    }
+    let mut group = c.benchmark_group(format!("day{}", day));
+    group.bench_function(format!("part1/{}", name), |b| {
+        b.iter(|| solver.part1(black_box(part1)))
+    });
+    group.bench_function(format!("part2/{}", name), |b| {
+        b.iter(|| solver.part2(black_box(part2)))
+    });
+    group.finish();
+}
+
+fn examples(c: &mut Criterion) {
+    for (day, part1, part2) in EXAMPLES {
+        bench_input(c, day, "example", part1, part2);
+    }
+}
+
+fn data(c: &mut Criterion) {
+    for day in 1..=DAYS {
+        let path = format!("{}/../day{}/data.txt", env!("CARGO_MANIFEST_DIR"), day);
+        //days without a bundled input are only benchmarked on their examples
+        if let Ok(input) = std::fs::read_to_string(path) {
+            bench_input(c, day, "data", &input, &input);
+        }
+    }
+}
+
+criterion_group!(benches, examples, data);
+criterion_main!(benches);
@@ -0,0 +1,101 @@
+use std::{collections::BTreeMap, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::record::Record;
+
+/// A confirmed answer for one part of one day's input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    pub answer: String,
+}
+
+/// Known-correct answers keyed by day, part and input hash.
+#[derive(Debug, Default)]
+pub struct AnswerStore {
+    answers: BTreeMap<(u8, u8, String), String>,
+}
+
+impl AnswerStore {
+    /// Reads the store from `path`, a missing file is an empty store.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err),
+        };
+        let answers: Vec<Answer> = serde_json::from_str(&content)?;
+        Ok(AnswerStore {
+            answers: answers
+                .into_iter()
+                .map(|answer| ((answer.day, answer.part, answer.input_hash), answer.answer))
+                .collect(),
+        })
+    }
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let answers: Vec<Answer> = self
+            .answers
+            .iter()
+            .map(|((day, part, input_hash), answer)| Answer {
+                day: *day,
+                part: *part,
+                input_hash: input_hash.clone(),
+                answer: answer.clone(),
+            })
+            .collect();
+        let mut content = serde_json::to_string_pretty(&answers)?;
+        content.push('\n');
+        std::fs::write(path, content)
+    }
+    pub fn get(&self, day: u8, part: u8, input_hash: &str) -> Option<&str> {
+        self.answers
+            .get(&(day, part, input_hash.to_string()))
+            .map(String::as_str)
+    }
+    /// Stores the answer of `record`, returning the one it replaced.
+    pub fn insert(&mut self, record: &Record) -> Option<String> {
+        self.answers.insert(
+            (record.day, record.part, record.input_hash.clone()),
+            record.answer.clone(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AnswerStore;
+    use crate::record::Record;
+
+    fn record(part: u8, answer: &str) -> Record {
+        Record {
+            day: 4,
+            part,
+            answer: answer.to_string(),
+            elapsed_ns: 0,
+            input_hash: "00ff".to_string(),
+        }
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.json", std::process::id()));
+        let mut store = AnswerStore::default();
+        assert_eq!(store.insert(&record(1, "13")), None);
+        assert_eq!(store.insert(&record(2, "31")), None);
+        assert_eq!(store.insert(&record(2, "30")), Some("31".to_string()));
+        store.save(&path).unwrap();
+        let loaded = AnswerStore::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.get(4, 1, "00ff"), Some("13"));
+        assert_eq!(loaded.get(4, 2, "00ff"), Some("30"));
+        assert_eq!(loaded.get(4, 2, "ff00"), None);
+    }
+    #[test]
+    fn missing_file_is_empty() {
+        let store = AnswerStore::load(std::path::Path::new("does/not/exist.json")).unwrap();
+        assert_eq!(store.get(1, 1, ""), None);
+    }
+}
@@ -0,0 +1,99 @@
+//! Properties the cargo-fuzz targets under `fuzz/` check on arbitrary inputs.
+//!
+//! ```text
+//! cargo +nightly fuzz run game fuzz/corpus/game fuzz/seeds/game
+//! ```
+//!
+//! `fuzz/seeds/<target>/` holds hand-written starting inputs, the fuzzer grows its own corpus in
+//! the first directory. Crashing inputs it reports under `fuzz/artifacts/<target>/` go to
+//! `fuzz/regressions/<target>/` once fixed, the tests below replay both so they keep being
+//! checked without the fuzzer.
+use std::{fmt::Debug, fmt::Display, str::FromStr};
+
+/// Parses `input`, and when it is valid checks that its displayed form parses back to the same value.
+pub fn round_trip<T>(input: &str)
+where
+    T: FromStr + Display + PartialEq + Debug,
+{
+    let Ok(value) = input.parse::<T>() else {
+        return;
+    };
+    let shown = value.to_string();
+    match shown.parse::<T>() {
+        Ok(again) => assert_eq!(again, value, "{:?} is displayed as {:?}", input, shown),
+        Err(_) => panic!(
+            "{:?} is displayed as {:?}, which does not parse",
+            input, shown
+        ),
+    }
+}
+
+pub fn subset(input: &str) {
+    round_trip::<day2::Subset>(input);
+}
+pub fn game(input: &str) {
+    round_trip::<day2::Game>(input);
+}
+pub fn card(input: &str) {
+    round_trip::<day4::Card>(input);
+}
+pub fn almanac(input: &str) {
+    round_trip::<day5::Almanac>(input);
+}
+/// Both record tables, the race by race one of part 1 and the single race one of part 2.
+pub fn record_table(input: &str) {
+    round_trip::<day6::part1::RecordTable>(input);
+    round_trip::<day6::part2::RecordTable>(input);
+}
+
+/// A property checked on every input the fuzzer tries.
+pub type Check = fn(&str);
+
+/// The check of every fuzz target, by target name.
+pub const TARGETS: [(&str, Check); 5] = [
+    ("subset", subset),
+    ("game", game),
+    ("card", card),
+    ("almanac", almanac),
+    ("record_table", record_table),
+];
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path};
+
+    use super::TARGETS;
+
+    #[test]
+    fn replay_seeds_and_regressions() {
+        let fuzz = Path::new(env!("CARGO_MANIFEST_DIR")).join("../fuzz");
+        for (target, check) in TARGETS {
+            let seeds = fs::read_dir(fuzz.join("seeds").join(target))
+                .unwrap_or_else(|err| panic!("no seeds for {}: {}", target, err));
+            //only targets the fuzzer found a crash for have regressions
+            let regressions = fs::read_dir(fuzz.join("regressions").join(target))
+                .into_iter()
+                .flatten();
+            for entry in seeds.chain(regressions) {
+                let path = entry.unwrap().path();
+                //the targets take `&str`, the fuzzer never hands them anything else
+                if let Ok(input) = String::from_utf8(fs::read(&path).unwrap()) {
+                    check(&input);
+                }
+            }
+        }
+    }
+    #[test]
+    fn examples_round_trip() {
+        for (target, check) in TARGETS {
+            let example = match target {
+                "subset" => "3 blue, 4 red",
+                "game" => "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+                "card" => "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53",
+                "almanac" => "seeds: 79 14 55 13\n\nseed-to-soil map:\n50 98 2\n52 50 48",
+                _ => "Time:      7  15   30\nDistance:  9  40  200",
+            };
+            check(example);
+        }
+    }
+}
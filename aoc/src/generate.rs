@@ -0,0 +1,108 @@
+//! Seeded random puzzle inputs, built so that their answers are known without running the solvers.
+//!
+//! A scale of 1 gives an input about the size of a real one, a scale of 100 one a hundred
+//! times larger, which is what the stress test feeds the solvers:
+//!
+//! ```text
+//! cargo test -p aoc --release -- --ignored stress
+//! ```
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+mod day1;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day9;
+
+/// A random puzzle input along with the answers it was built to have.
+#[derive(Debug, Clone)]
+pub struct Generated {
+    pub input: String,
+    /// `None` when the answer cannot be known cheaply, or would not fit the solver.
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// A random input for `day`, the same for the same `seed` and `scale`, `None` for days without a generator.
+pub fn generate(day: u8, seed: u64, scale: usize) -> Option<Generated> {
+    let rng = &mut ChaCha8Rng::seed_from_u64(seed);
+    let scale = scale.max(1);
+    match day {
+        1 => Some(day1::generate(rng, scale)),
+        2 => Some(day2::generate(rng, scale)),
+        3 => Some(day3::generate(rng, scale)),
+        4 => Some(day4::generate(rng, scale)),
+        5 => Some(day5::generate(rng, scale)),
+        6 => Some(day6::generate(rng, scale)),
+        9 => Some(day9::generate(rng, scale)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{generate, Generated};
+    use crate::{solution, DAYS};
+
+    /// Solves `generated` with the solver of `day` and compares with the answers it was built to have.
+    fn check(day: u8, seed: u64, generated: &Generated) {
+        let solution = solution(day);
+        if let Some(problems) = solution.validate(&generated.input) {
+            assert!(
+                problems.is_empty(),
+                "day {} seed {}: {:?}",
+                day,
+                seed,
+                problems
+            );
+        }
+        let expected = [(1, &generated.part1), (2, &generated.part2)];
+        for (part, expected) in expected {
+            let Some(expected) = expected else { continue };
+            let mut input = generated.input.as_bytes();
+            let answer = match part {
+                1 => solution.part1_from_reader(&mut input),
+                _ => solution.part2_from_reader(&mut input),
+            };
+            let answer = answer.unwrap_or_else(|err| panic!("day {} seed {}: {}", day, seed, err));
+            assert_eq!(&answer, expected, "day {} part {} seed {}", day, part, seed);
+        }
+    }
+
+    #[test]
+    fn same_seed_same_input() {
+        for day in 1..=DAYS {
+            let (first, second) = (generate(day, 7, 1), generate(day, 7, 1));
+            assert_eq!(
+                first.map(|generated| generated.input),
+                second.map(|generated| generated.input)
+            );
+        }
+        assert_ne!(
+            generate(4, 1, 1).unwrap().input,
+            generate(4, 2, 1).unwrap().input
+        );
+    }
+    #[test]
+    fn solvers_find_the_generated_answers() {
+        for day in 1..=DAYS {
+            for seed in 0..4 {
+                if let Some(generated) = generate(day, seed, 1) {
+                    check(day, seed, &generated);
+                }
+            }
+        }
+    }
+    #[test]
+    #[ignore = "slow outside of release builds"]
+    fn stress_at_a_hundred_times_the_size() {
+        for day in 1..=DAYS {
+            if let Some(generated) = generate(day, 2023, 100) {
+                check(day, 2023, &generated);
+            }
+        }
+    }
+}
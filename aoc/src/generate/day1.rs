@@ -0,0 +1,56 @@
+use rand::{seq::SliceRandom, Rng};
+
+use super::Generated;
+
+const LINES: usize = 1000;
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+//none of these letters appear in a spelled digit, so noise never hides one
+const NOISE: &[u8] = b"abcdjklmpqy";
+
+fn noise(rng: &mut impl Rng, line: &mut String, max: usize) {
+    for _ in 0..rng.gen_range(0..=max) {
+        line.push(*NOISE.choose(rng).unwrap() as char);
+    }
+}
+
+/// Calibration lines mixing digits, spelled digits and noise, at least one plain digit per line.
+///
+/// Spelled digits sometimes share a letter with the next one, like `oneight`, both counting.
+pub(super) fn generate(rng: &mut impl Rng, scale: usize) -> Generated {
+    let mut input = String::new();
+    let (mut part1, mut part2) = (0u64, 0u64);
+    for _ in 0..LINES * scale {
+        //(value, spelled) of every digit of the line
+        let mut digits: Vec<(u32, bool)> = (0..rng.gen_range(1..=6))
+            .map(|_| (rng.gen_range(1..=9), rng.gen_bool(0.5)))
+            .collect();
+        let plain = rng.gen_range(0..digits.len());
+        digits[plain].1 = false;
+        let mut line = String::new();
+        noise(rng, &mut line, 4);
+        for (value, spelled) in &digits {
+            if !spelled {
+                line.push(char::from_digit(*value, 10).unwrap());
+            } else {
+                let word = WORDS[*value as usize - 1];
+                let shared = line.ends_with(&word[..1]) && rng.gen_bool(0.5);
+                line.push_str(if shared { &word[1..] } else { word });
+            }
+            noise(rng, &mut line, 3);
+        }
+        input += &line;
+        input.push('\n');
+        let mut plain = digits.iter().filter(|(_, spelled)| !spelled);
+        let first = plain.next().unwrap().0;
+        let last = plain.next_back().map_or(first, |(value, _)| *value);
+        part1 += (first * 10 + last) as u64;
+        part2 += (digits[0].0 * 10 + digits[digits.len() - 1].0) as u64;
+    }
+    Generated {
+        input,
+        part1: Some(part1.to_string()),
+        part2: Some(part2.to_string()),
+    }
+}
@@ -0,0 +1,45 @@
+use rand::{seq::SliceRandom, Rng};
+
+use super::Generated;
+
+const GAMES: usize = 100;
+const COLOURS: [&str; 3] = ["red", "green", "blue"];
+const BAG: [u32; 3] = [12, 13, 14];
+
+/// Games of up to six draws, each showing up to 20 cubes of a random subset of the colours.
+pub(super) fn generate(rng: &mut impl Rng, scale: usize) -> Generated {
+    let mut input = String::new();
+    let (mut part1, mut part2) = (0u64, 0u64);
+    for id in 1..=GAMES * scale {
+        let mut minimum = [0; 3];
+        let mut draws = Vec::new();
+        for _ in 0..rng.gen_range(1..=6) {
+            let mut colours = [0, 1, 2];
+            colours.shuffle(rng);
+            let shown = &colours[..rng.gen_range(1..=3)];
+            let cubes: Vec<String> = shown
+                .iter()
+                .map(|colour| {
+                    let count = rng.gen_range(1..=20);
+                    minimum[*colour] = minimum[*colour].max(count);
+                    format!("{} {}", count, COLOURS[*colour])
+                })
+                .collect();
+            draws.push(cubes.join(", "));
+        }
+        input += &format!("Game {}: {}\n", id, draws.join("; "));
+        if minimum
+            .iter()
+            .zip(BAG)
+            .all(|(count, limit)| *count <= limit)
+        {
+            part1 += id as u64;
+        }
+        part2 += minimum.iter().map(|count| *count as u64).product::<u64>();
+    }
+    Generated {
+        input,
+        part1: Some(part1.to_string()),
+        part2: Some(part2.to_string()),
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+
+use rand::{seq::SliceRandom, Rng};
+
+use super::Generated;
+
+const SIDE: usize = 140;
+const SYMBOLS: &[u8] = b"*#+$/@=%-&";
+
+/// A square schematic of numbers up to 999 and symbols, a third of the symbols being `*`.
+///
+/// The answers are read back from the finished grid, every number checking the cells around it.
+pub(super) fn generate(rng: &mut impl Rng, scale: usize) -> Generated {
+    let side = (SIDE as f64 * (scale as f64).sqrt()).round() as usize;
+    let mut grid = vec![vec![b'.'; side]; side];
+    //(row, start, end, value) of every number
+    let mut numbers = Vec::new();
+    for (row, line) in grid.iter_mut().enumerate() {
+        let mut column = 0;
+        while column < side {
+            if rng.gen_bool(0.12) {
+                let value: u32 = rng.gen_range(1..1000);
+                let digits = value.to_string();
+                let end = column + digits.len();
+                if end <= side {
+                    line[column..end].copy_from_slice(digits.as_bytes());
+                    numbers.push((row, column, end, value));
+                }
+                //leave a gap so that numbers never run into each other
+                column = end + 1;
+            } else {
+                if rng.gen_bool(0.08) {
+                    line[column] = if rng.gen_bool(1.0 / 3.0) {
+                        b'*'
+                    } else {
+                        *SYMBOLS.choose(rng).unwrap()
+                    };
+                }
+                column += 1;
+            }
+        }
+    }
+    let mut part1 = 0u64;
+    //numbers around each `*`, by position
+    let mut gears: BTreeMap<(usize, usize), Vec<u32>> = BTreeMap::new();
+    for (row, start, end, value) in &numbers {
+        let mut is_part = false;
+        let (top, left) = (row.saturating_sub(1), start.saturating_sub(1));
+        for (r, line) in (top..).zip(&grid[top..=(row + 1).min(side - 1)]) {
+            for (c, cell) in (left..).zip(&line[left..=(*end).min(side - 1)]) {
+                let cell = *cell;
+                if cell == b'.' || cell.is_ascii_digit() {
+                    continue;
+                }
+                is_part = true;
+                if cell == b'*' {
+                    gears.entry((r, c)).or_default().push(*value);
+                }
+            }
+        }
+        if is_part {
+            part1 += *value as u64;
+        }
+    }
+    let part2: u64 = gears
+        .values()
+        .filter(|values| values.len() == 2)
+        .map(|values| values[0] as u64 * values[1] as u64)
+        .sum();
+    let mut input = String::new();
+    for line in &grid {
+        input += std::str::from_utf8(line).unwrap();
+        input.push('\n');
+    }
+    Generated {
+        input,
+        part1: Some(part1.to_string()),
+        part2: Some(part2.to_string()),
+    }
+}
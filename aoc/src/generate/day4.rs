@@ -0,0 +1,61 @@
+use rand::{
+    seq::{index, SliceRandom},
+    Rng,
+};
+
+use super::Generated;
+
+const CARDS: usize = 205;
+const WINNING: usize = 10;
+const SCRATCHED: usize = 25;
+
+/// Cards of 10 winning and 25 scratched numbers out of 1 to 99.
+///
+/// Cards come in runs of up to 11 whose wins never reach past the run, so copies stay
+/// bounded however many cards there are, as in the real inputs.
+pub(super) fn generate(rng: &mut impl Rng, scale: usize) -> Generated {
+    let cards = CARDS * scale;
+    let mut wins = Vec::with_capacity(cards);
+    while wins.len() < cards {
+        let run = rng.gen_range(1..=WINNING + 1).min(cards - wins.len());
+        wins.extend((0..run).map(|idx| rng.gen_range(0..=(run - 1 - idx).min(WINNING))));
+    }
+    let mut input = String::new();
+    for (idx, wins) in wins.iter().enumerate() {
+        let numbers: Vec<usize> = index::sample(rng, 99, WINNING + SCRATCHED - wins)
+            .into_iter()
+            .map(|number| number + 1)
+            .collect();
+        let (winning, others) = numbers.split_at(WINNING);
+        let mut scratched: Vec<usize> = winning[..*wins].iter().chain(others).copied().collect();
+        scratched.shuffle(rng);
+        let format = |numbers: &[usize]| -> Vec<String> {
+            numbers
+                .iter()
+                .map(|number| format!("{:>2}", number))
+                .collect()
+        };
+        input += &format!(
+            "Card {:>3}: {} | {}\n",
+            idx + 1,
+            format(winning).join(" "),
+            format(&scratched).join(" ")
+        );
+    }
+    let part1: u64 = wins
+        .iter()
+        .filter(|wins| **wins > 0)
+        .map(|wins| 1 << (wins - 1))
+        .sum();
+    let mut instances = vec![1u64; cards];
+    for idx in 0..cards {
+        for next in idx + 1..=idx + wins[idx] {
+            instances[next] += instances[idx];
+        }
+    }
+    Generated {
+        input,
+        part1: Some(part1.to_string()),
+        part2: Some(instances.iter().sum::<u64>().to_string()),
+    }
+}
@@ -0,0 +1,167 @@
+use day5::MAP_NAMES;
+use rand::{seq::SliceRandom, Rng};
+
+use super::Generated;
+
+const SEED_RANGES: usize = 10;
+//longest seed range of a real sized input, ranges shrink as they multiply to cover as much
+const SPREAD: u64 = 1 << 27;
+const SEGMENTS: usize = 30;
+const LIMIT: u64 = 1 << 32;
+
+/// Pieces of a map as `(start, other start, length)`, sorted by start.
+type Pieces = Vec<(u64, u64, u64)>;
+
+/// Where `value` lands through the piece covering it.
+fn lookup(pieces: &Pieces, value: u64) -> u64 {
+    let (start, other, _) = pieces[pieces.partition_point(|piece| piece.0 <= value) - 1];
+    other + (value - start)
+}
+
+/// A map cutting `0..LIMIT` into segments and shuffling them, some staying in place.
+///
+/// Segments staying in place are left out of the listing like the real gaps, and
+/// the others only move between them. Every value has a single source, so the map
+/// can be walked backwards.
+struct Shuffle {
+    forward: Pieces,
+    backward: Pieces,
+}
+
+impl Shuffle {
+    fn new(rng: &mut impl Rng, segments: usize) -> Self {
+        let mut cuts: Vec<u64> = (1..segments).map(|_| rng.gen_range(1..LIMIT)).collect();
+        cuts.extend([0, LIMIT]);
+        cuts.sort_unstable();
+        cuts.dedup();
+        let segments: Vec<(u64, u64)> = cuts
+            .windows(2)
+            .map(|cut| (cut[0], cut[1] - cut[0]))
+            .collect();
+        let mut order: Vec<usize> = Vec::with_capacity(segments.len());
+        let mut moving = Vec::new();
+        for idx in 0..segments.len() {
+            if rng.gen_bool(0.2) {
+                moving.shuffle(rng);
+                order.append(&mut moving);
+                order.push(idx);
+            } else {
+                moving.push(idx);
+            }
+        }
+        moving.shuffle(rng);
+        order.append(&mut moving);
+        let mut forward = Vec::with_capacity(segments.len());
+        let mut destination = 0;
+        for idx in order {
+            let (source, length) = segments[idx];
+            forward.push((source, destination, length));
+            destination += length;
+        }
+        forward.sort_unstable();
+        let mut backward: Pieces = forward
+            .iter()
+            .map(|(source, destination, length)| (*destination, *source, *length))
+            .collect();
+        backward.sort_unstable();
+        Shuffle { forward, backward }
+    }
+    /// `<destination> <source> <length>` lines for every moved segment, in random order.
+    fn listing(&self, rng: &mut impl Rng) -> Vec<String> {
+        let mut moved: Vec<&(u64, u64, u64)> = self
+            .forward
+            .iter()
+            .filter(|(source, destination, _)| source != destination)
+            .collect();
+        if moved.is_empty() {
+            //a map lists at least one mapping, even one leaving its values in place
+            moved.push(&self.forward[0]);
+        }
+        moved.shuffle(rng);
+        moved
+            .into_iter()
+            .map(|(source, destination, length)| format!("{} {} {}", destination, source, length))
+            .collect()
+    }
+}
+
+/// Longest seed range at `scale`, at least one seed however large the scale.
+fn longest_seed_range(scale: usize) -> u64 {
+    (SPREAD / scale as u64).max(1)
+}
+/// An almanac of seed ranges across `0..2^32` and seven maps shuffling that whole space.
+///
+/// Part 2 only needs the locations of the seeds starting a range and of those reaching
+/// the start of a segment in one of the maps: everything in between moves in one piece.
+pub(super) fn generate(rng: &mut impl Rng, scale: usize) -> Generated {
+    let maps: Vec<Shuffle> = MAP_NAMES
+        .iter()
+        .map(|_| Shuffle::new(rng, SEGMENTS * scale))
+        .collect();
+    let ranges: Vec<(u64, u64)> = (0..SEED_RANGES * scale)
+        .map(|_| {
+            let start = rng.gen_range(0..LIMIT - 1);
+            (
+                start,
+                rng.gen_range(1..=(LIMIT - start).min(longest_seed_range(scale))),
+            )
+        })
+        .collect();
+    let location = |seed: u64| {
+        maps.iter()
+            .fold(seed, |value, map| lookup(&map.forward, value))
+    };
+    let part1 = ranges
+        .iter()
+        .flat_map(|(start, length)| [*start, *length])
+        .map(location)
+        .min();
+    let mut candidates: Vec<u64> = ranges.iter().map(|(start, _)| *start).collect();
+    for (level, map) in maps.iter().enumerate() {
+        candidates.extend(map.forward.iter().map(|(source, _, _)| {
+            maps[..level]
+                .iter()
+                .rev()
+                .fold(*source, |value, map| lookup(&map.backward, value))
+        }));
+    }
+    let part2 = candidates
+        .into_iter()
+        .filter(|seed| {
+            ranges
+                .iter()
+                .any(|(start, length)| (*start..start + length).contains(seed))
+        })
+        .map(location)
+        .min();
+    let seeds: Vec<String> = ranges
+        .iter()
+        .map(|(start, length)| format!("{} {}", start, length))
+        .collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+    for (name, map) in MAP_NAMES.iter().zip(&maps) {
+        input += &format!("\n{} map:\n", name);
+        for line in map.listing(rng) {
+            input += &line;
+            input.push('\n');
+        }
+    }
+    Generated {
+        input,
+        part1: part1.map(|location| location.to_string()),
+        part2: part2.map(|location| location.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{longest_seed_range, SPREAD};
+
+    #[test]
+    fn seed_ranges_never_shrink_to_nothing() {
+        assert_eq!(longest_seed_range(1), SPREAD);
+        assert_eq!(longest_seed_range(1 << 27), 1);
+        assert_eq!(longest_seed_range((1 << 27) + 1), 1);
+        assert_eq!(longest_seed_range(usize::MAX), 1);
+    }
+}
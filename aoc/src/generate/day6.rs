@@ -0,0 +1,60 @@
+use rand::Rng;
+
+use super::Generated;
+
+const RACES: usize = 4;
+
+/// Ways to beat `record` in a race of `time`, searching the first winning hold time.
+///
+/// Distances grow up to a hold of `time / 2`, so the first winning hold is found by bisection.
+fn ways(time: u128, record: u128) -> u128 {
+    let (mut low, mut high) = (0, time / 2 + 1);
+    while low < high {
+        let hold = (low + high) / 2;
+        if hold * (time - hold) > record {
+            high = hold;
+        } else {
+            low = hold + 1;
+        }
+    }
+    if low > time / 2 {
+        0
+    } else {
+        time - 2 * low + 1
+    }
+}
+
+/// Races of 2 to 100 ms, every one of them winnable.
+///
+/// Races are made to have a single way to win once the product of the ways would overflow.
+/// Part 2 is only known while the times and distances still fit once their digits are joined.
+pub(super) fn generate(rng: &mut impl Rng, scale: usize) -> Generated {
+    let mut races = Vec::new();
+    let mut part1: u64 = 1;
+    for _ in 0..RACES * scale {
+        let mut time: u64 = rng.gen_range(2..=100);
+        let mut record = rng.gen_range(0..(time / 2) * (time - time / 2));
+        let mut race_ways = ways(time as u128, record as u128) as u64;
+        if part1.checked_mul(race_ways).is_none() {
+            time += time % 2;
+            record = time * time / 4 - 1;
+            race_ways = 1;
+        }
+        part1 *= race_ways;
+        races.push((time, record));
+    }
+    let joined = |values: Vec<String>| values.concat().parse::<u64>().ok();
+    let time = joined(races.iter().map(|(time, _)| time.to_string()).collect());
+    let record = joined(races.iter().map(|(_, record)| record.to_string()).collect());
+    let part2 = time
+        .zip(record)
+        .map(|(time, record)| ways(time as u128, record as u128).to_string());
+    let column = |value: u64| format!(" {:>5}", value);
+    let times: String = races.iter().map(|(time, _)| column(*time)).collect();
+    let records: String = races.iter().map(|(_, record)| column(*record)).collect();
+    Generated {
+        input: format!("Time:    {}\nDistance:{}\n", times, records),
+        part1: Some(part1.to_string()),
+        part2,
+    }
+}
@@ -0,0 +1,39 @@
+use rand::Rng;
+
+use super::Generated;
+
+const HISTORIES: usize = 200;
+const VALUES: i64 = 21;
+
+/// `n` choose `k` for any integer `n`, negative ones included.
+fn binomial(n: i64, k: i64) -> i128 {
+    (0..k).fold(1, |acc, idx| acc * (n - idx) as i128 / (idx + 1) as i128)
+}
+
+/// Histories of 21 values of polynomials of degree up to 12, picked by the leading
+/// differences of their first value so that every value is an integer.
+pub(super) fn generate(rng: &mut impl Rng, scale: usize) -> Generated {
+    let mut input = String::new();
+    let (mut part1, mut part2) = (0i128, 0i128);
+    for _ in 0..HISTORIES * scale {
+        let differences: Vec<i128> = (0..=rng.gen_range(0..=12))
+            .map(|_| rng.gen_range(-9..=9))
+            .collect();
+        let value = |x: i64| -> i128 {
+            (0..)
+                .zip(&differences)
+                .map(|(k, difference)| difference * binomial(x, k))
+                .sum()
+        };
+        let history: Vec<String> = (0..VALUES).map(|x| value(x).to_string()).collect();
+        input += &history.join(" ");
+        input.push('\n');
+        part1 += value(VALUES);
+        part2 += value(-1);
+    }
+    Generated {
+        input,
+        part1: Some(part1.to_string()),
+        part2: Some(part2.to_string()),
+    }
+}
@@ -0,0 +1,22 @@
+use common::Solution;
+
+pub mod fuzz;
+pub mod generate;
+
+pub const DAYS: u8 = 9;
+
+/// The solver of `day`, panics outside of `1..=DAYS`.
+pub fn solution(day: u8) -> &'static dyn Solution {
+    match day {
+        1 => &day1::Day1,
+        2 => &day2::Day2,
+        3 => &day3::Day3,
+        4 => &day4::Day4,
+        5 => &day5::Day5,
+        6 => &day6::Day6,
+        7 => &day7::Day7,
+        8 => &day8::Day8,
+        9 => &day9::Day9,
+        _ => unreachable!("day {} has no solution", day),
+    }
+}
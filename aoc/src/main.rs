@@ -0,0 +1,339 @@
+use std::{
+    io::{self, BufReader, Read},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Instant,
+};
+
+use answers::AnswerStore;
+use aoc::{generate::generate, solution, DAYS};
+use clap::{Parser, Subcommand, ValueEnum};
+use record::{HashingReader, Record};
+
+mod answers;
+mod record;
+
+const ANSWERS: &str = "answers.json";
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2023 solvers")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, or every day when no day is given
+    Run {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=DAYS as i64))]
+        day: Option<u8>,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input to use instead of dayN/data.txt, `-` for stdin, optionally gzip compressed
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Show how the day handles this value instead of solving it, e.g. a day 5 seed, as plain text
+        #[arg(long, requires = "day", conflicts_with_all = ["part", "format"], value_name = "VALUE")]
+        explain: Option<u64>,
+    },
+    /// Run every day on its dayN/data.txt and compare with the recorded answers
+    Verify {
+        #[arg(long, default_value = ANSWERS)]
+        answers: PathBuf,
+        /// Accept answers whose input has no recorded answer instead of failing on them
+        #[arg(long)]
+        allow_unrecorded: bool,
+    },
+    /// Check the inputs of the selected days against their grammar, reporting every problem
+    Validate {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=DAYS as i64))]
+        day: Option<u8>,
+        /// Puzzle input to use instead of dayN/data.txt, `-` for stdin, optionally gzip compressed
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+    },
+    /// Print a random input for a day, its expected answers going to stderr
+    Generate {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=DAYS as i64))]
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Size of the input relative to a real one
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        scale: u64,
+    },
+    /// Run the selected days and store their answers as the confirmed ones
+    Record {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=DAYS as i64))]
+        day: Option<u8>,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input to use instead of dayN/data.txt, `-` for stdin, optionally gzip compressed
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        #[arg(long, default_value = ANSWERS)]
+        answers: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// One `day N part P: answer` line per part
+    Text,
+    /// One JSON record per part with its answer, timing and input hash
+    Json,
+}
+
+fn default_input(day: u8) -> PathBuf {
+    PathBuf::from(format!("day{}/data.txt", day))
+}
+
+fn selected_days(day: Option<u8>) -> Vec<u8> {
+    match day {
+        Some(day) => vec![day],
+        None => (1..=DAYS).collect(),
+    }
+}
+
+fn solve(day: u8, parts: &[u8], input: &Path) -> Result<Vec<Record>, String> {
+    if input == Path::new("-") && parts.len() > 1 {
+        return Err(format!("day {}: stdin can only be read once, pick a --part", day));
+    }
+    let cannot_read =
+        |err: io::Error| format!("day {}: cannot read {}: {}", day, input.display(), err);
+    let solution = solution(day);
+    parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let mut reader = BufReader::new(HashingReader::new(
+                common::input::open(input).map_err(cannot_read)?,
+            ));
+            let answer = match part {
+                1 => solution.part1_from_reader(&mut reader),
+                _ => solution.part2_from_reader(&mut reader),
+            }
+            .map_err(|err| format!("day {} part {}: {}: {}", day, part, input.display(), err))?;
+            let elapsed_ns = start.elapsed().as_nanos() as u64;
+            //the hash covers the whole input even when the solver stopped early
+            io::copy(&mut reader, &mut io::sink()).map_err(cannot_read)?;
+            Ok(Record {
+                day,
+                part: *part,
+                answer,
+                elapsed_ns,
+                input_hash: reader.get_ref().hash().hex(),
+            })
+        })
+        .collect()
+}
+
+/// Solves the selected days and parts, handing every record to `report`.
+///
+/// Without `input`, days whose dayN/data.txt is missing are skipped, saying so on stderr to keep
+/// stdout to the records.
+fn for_each_record<F>(
+    days: Vec<u8>,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    mut report: F,
+) -> ExitCode
+where
+    F: FnMut(Record) -> bool,
+{
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let mut status = ExitCode::SUCCESS;
+    for day in days {
+        let input = match &input {
+            Some(input) => input.clone(),
+            None if default_input(day).exists() => default_input(day),
+            None => {
+                eprintln!("day {}: skipped, {} is missing", day, default_input(day).display());
+                continue;
+            }
+        };
+        match solve(day, &parts, &input) {
+            Ok(records) => {
+                for record in records {
+                    if !report(record) {
+                        status = ExitCode::FAILURE;
+                    }
+                }
+            }
+            Err(err) => {
+                eprintln!("{}", err);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
+
+fn explain(day: u8, input: Option<PathBuf>, value: u64) -> ExitCode {
+    let input = input.unwrap_or_else(|| default_input(day));
+    let mut content = String::new();
+    if let Err(err) =
+        common::input::open(&input).and_then(|mut reader| reader.read_to_string(&mut content))
+    {
+        eprintln!("day {}: cannot read {}: {}", day, input.display(), err);
+        return ExitCode::FAILURE;
+    }
+    match solution(day).explain(&content, value) {
+        Some(Ok(report)) => {
+            print!("{}", report);
+            ExitCode::SUCCESS
+        }
+        Some(Err(err)) => {
+            eprintln!("day {}: {}: {}", day, input.display(), err);
+            ExitCode::FAILURE
+        }
+        None => {
+            eprintln!("day {} cannot explain its answers", day);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn validate(days: Vec<u8>, input: Option<PathBuf>) -> ExitCode {
+    let mut status = ExitCode::SUCCESS;
+    for day in days {
+        let input = match &input {
+            Some(input) => input.clone(),
+            None if default_input(day).exists() => default_input(day),
+            None => {
+                println!("day {}: skipped, {} is missing", day, default_input(day).display());
+                continue;
+            }
+        };
+        let mut content = String::new();
+        if let Err(err) =
+            common::input::open(&input).and_then(|mut reader| reader.read_to_string(&mut content))
+        {
+            eprintln!("day {}: cannot read {}: {}", day, input.display(), err);
+            status = ExitCode::FAILURE;
+            continue;
+        }
+        match solution(day).validate(&content) {
+            Some(problems) if problems.is_empty() => println!("day {}: ok", day),
+            Some(problems) => {
+                for problem in &problems {
+                    println!("day {}: {}: {}", day, input.display(), problem);
+                }
+                let plural = if problems.len() == 1 { "" } else { "s" };
+                println!("day {}: {} problem{}", day, problems.len(), plural);
+                status = ExitCode::FAILURE;
+            }
+            None => println!("day {}: skipped, no grammar to check", day),
+        }
+    }
+    status
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run {
+            day: Some(day),
+            input,
+            explain: Some(value),
+            ..
+        } => explain(day, input, value),
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            ..
+        } => for_each_record(selected_days(day), part, input, |record| {
+            match format {
+                Format::Text => {
+                    println!("day {} part {}: {}", record.day, record.part, record.answer)
+                }
+                Format::Json => println!("{}", serde_json::to_string(&record).unwrap()),
+            }
+            true
+        }),
+        Command::Validate { day, input } => validate(selected_days(day), input),
+        Command::Generate { day, seed, scale } => match generate(day, seed, scale as usize) {
+            Some(generated) => {
+                print!("{}", generated.input);
+                for (part, answer) in [(1, generated.part1), (2, generated.part2)] {
+                    let answer = answer.unwrap_or_else(|| "unknown".to_string());
+                    eprintln!("day {} part {}: {}", day, part, answer);
+                }
+                ExitCode::SUCCESS
+            }
+            None => {
+                eprintln!("day {} has no input generator", day);
+                ExitCode::FAILURE
+            }
+        },
+        Command::Verify {
+            answers,
+            allow_unrecorded,
+        } => {
+            let store = match AnswerStore::load(&answers) {
+                Ok(store) => store,
+                Err(err) => {
+                    eprintln!("cannot load {}: {}", answers.display(), err);
+                    return ExitCode::FAILURE;
+                }
+            };
+            for_each_record(selected_days(None), None, None, |record| {
+                let prefix = format!("day {} part {}", record.day, record.part);
+                match store.get(record.day, record.part, &record.input_hash) {
+                    Some(expected) if expected == record.answer => {
+                        println!("{}: ok", prefix);
+                        true
+                    }
+                    Some(expected) => {
+                        println!("{}: expected {}, got {}", prefix, expected, record.answer);
+                        false
+                    }
+                    None => {
+                        println!(
+                            "{}: no recorded answer for input {}, got {}",
+                            prefix, record.input_hash, record.answer
+                        );
+                        allow_unrecorded
+                    }
+                }
+            })
+        }
+        Command::Record {
+            day,
+            part,
+            input,
+            answers,
+        } => {
+            let mut store = match AnswerStore::load(&answers) {
+                Ok(store) => store,
+                Err(err) => {
+                    eprintln!("cannot load {}: {}", answers.display(), err);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let mut status = for_each_record(selected_days(day), part, input, |record| {
+                match store.insert(&record) {
+                    Some(previous) if previous != record.answer => println!(
+                        "day {} part {}: {} (was {})",
+                        record.day, record.part, record.answer, previous
+                    ),
+                    _ => println!("day {} part {}: {}", record.day, record.part, record.answer),
+                }
+                true
+            });
+            if let Err(err) = store.save(&answers) {
+                eprintln!("cannot save {}: {}", answers.display(), err);
+                status = ExitCode::FAILURE;
+            }
+            status
+        }
+    }
+}
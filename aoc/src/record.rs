@@ -0,0 +1,102 @@
+use std::io::{self, Read};
+
+use serde::Serialize;
+
+/// One answered puzzle part, as reported by `aoc run`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub elapsed_ns: u64,
+    pub input_hash: String,
+}
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// FNV-1a of the raw input, stable across platforms and Rust releases.
+#[derive(Debug, Clone, Copy)]
+pub struct InputHash(u64);
+
+impl Default for InputHash {
+    fn default() -> Self {
+        InputHash(FNV_OFFSET)
+    }
+}
+impl InputHash {
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.0 = bytes.iter().fold(self.0, |hash, byte| {
+            (hash ^ *byte as u64).wrapping_mul(FNV_PRIME)
+        });
+    }
+    pub fn hex(&self) -> String {
+        format!("{:016x}", self.0)
+    }
+}
+
+/// Hashes every byte read through it, so streamed inputs get the same hash as whole ones.
+pub struct HashingReader<R> {
+    inner: R,
+    hash: InputHash,
+}
+impl<R> HashingReader<R> {
+    pub fn new(inner: R) -> Self {
+        HashingReader {
+            inner,
+            hash: InputHash::default(),
+        }
+    }
+    pub fn hash(&self) -> InputHash {
+        self.hash
+    }
+}
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.hash.update(&buf[..read]);
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Read;
+
+    use super::{HashingReader, InputHash, Record};
+
+    fn input_hash(input: &str) -> String {
+        let mut hash = InputHash::default();
+        hash.update(input.as_bytes());
+        hash.hex()
+    }
+
+    #[test]
+    fn hash_known_vectors() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+    #[test]
+    fn hash_while_reading() {
+        let input = "Time: 7 15 30\nDistance: 9 40 200\n";
+        let mut reader = HashingReader::new(input.as_bytes());
+        let mut first = [0; 5];
+        reader.read_exact(&mut first).unwrap();
+        reader.read_to_end(&mut Vec::new()).unwrap();
+        assert_eq!(reader.hash().hex(), input_hash(input));
+    }
+    #[test]
+    fn serialize_record() {
+        let record = Record {
+            day: 5,
+            part: 2,
+            answer: "46".to_string(),
+            elapsed_ns: 1200,
+            input_hash: input_hash("a"),
+        };
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"day":5,"part":2,"answer":"46","elapsed_ns":1200,"input_hash":"af63dc4c8601ec8c"}"#
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt;
+
+/// Where and why a puzzle input could not be parsed.
+///
+/// Lines and columns are 1-based, the snippet holds the text found at the
+/// offending position (empty at the end of the input).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+    pub expected: String,
+}
+
+const SNIPPET_LEN: usize = 20;
+
+impl ParseError {
+    /// Builds an error pointing at `rest`, which must be a slice of `source`.
+    pub fn at(source: &str, rest: &str, expected: impl Into<String>) -> Self {
+        let offset = (rest.as_ptr() as usize)
+            .checked_sub(source.as_ptr() as usize)
+            .filter(|offset| *offset <= source.len())
+            .unwrap_or(0);
+        let before = &source[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        let column = source[line_start..offset].chars().count() + 1;
+        let snippet = source[offset..]
+            .lines()
+            .next()
+            .unwrap_or("")
+            .chars()
+            .take(SNIPPET_LEN)
+            .collect();
+        ParseError {
+            line,
+            column,
+            snippet,
+            expected: expected.into(),
+        }
+    }
+    /// Converts a nom failure while parsing `source` into a positioned error.
+    pub fn nom(
+        source: &str,
+        err: nom::Err<nom::error::Error<&str>>,
+        expected: impl Into<String>,
+    ) -> Self {
+        match err {
+            nom::Err::Error(err) | nom::Err::Failure(err) => {
+                ParseError::at(source, err.input, expected)
+            }
+            nom::Err::Incomplete(_) => ParseError::at(source, &source[source.len()..], expected),
+        }
+    }
+    /// Moves an error raised by a single line parser to `line` of the whole input.
+    pub fn in_line(mut self, line: usize) -> Self {
+        self.line += line - 1;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        if self.snippet.is_empty() {
+            write!(f, "found end of line")
+        } else {
+            write!(f, "found \"{}\"", self.snippet)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod test {
+    use super::ParseError;
+
+    #[test]
+    fn position_of_a_slice() {
+        let source = "Time: 7 15\nDistance: 9 x0";
+        let rest = &source[source.find('x').unwrap()..];
+        let err = ParseError::at(source, rest, "number");
+        assert_eq!(err.line, 2);
+        assert_eq!(err.column, 13);
+        assert_eq!(err.snippet, "x0");
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 13: expected number, found \"x0\""
+        );
+    }
+    #[test]
+    fn position_at_the_end() {
+        let source = "Card 1:";
+        let err = ParseError::at(source, &source[source.len()..], "numbers");
+        assert_eq!((err.line, err.column), (1, 8));
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 8: expected numbers, found end of line"
+        );
+    }
+    #[test]
+    fn shifted_to_a_line() {
+        let source = "abc";
+        let err = ParseError::at(source, &source[2..], "digit").in_line(4);
+        assert_eq!((err.line, err.column), (4, 3));
+    }
+}
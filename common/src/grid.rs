@@ -0,0 +1,200 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::ParseError;
+
+/// A `(row, column)` coordinate inside a [`Grid`].
+pub type Position = (usize, usize);
+
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+/// A rectangular grid of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row-major order.
+    ///
+    /// Panics when `cells` does not hold exactly `width * height` elements.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "grid cells do not fill the grid");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn get(&self, (row, column): Position) -> Option<&T> {
+        if row >= self.height || column >= self.width {
+            return None;
+        }
+        self.cells.get(row * self.width + column)
+    }
+    pub fn get_mut(&mut self, (row, column): Position) -> Option<&mut T> {
+        if row >= self.height || column >= self.width {
+            return None;
+        }
+        self.cells.get_mut(row * self.width + column)
+    }
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width.max(1))
+    }
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> + '_ {
+        (0..self.height).filter_map(move |row| self.get((row, column)))
+    }
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|column| self.column(column))
+    }
+    /// Every position of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+    /// The up to 4 positions sharing an edge with `position`.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offsets(position, &ORTHOGONAL)
+    }
+    /// The up to 8 positions sharing an edge or a corner with `position`.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offsets(position, &SURROUNDING)
+    }
+    pub fn map<U, F>(self, f: F) -> Grid<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.into_iter().map(f).collect(),
+        }
+    }
+    fn offsets<'a>(
+        &'a self,
+        (row, column): Position,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Position> + 'a {
+        offsets.iter().filter_map(move |(dr, dc)| {
+            let neighbour = (row.checked_add_signed(*dr)?, column.checked_add_signed(*dc)?);
+            self.get(neighbour).map(|_| neighbour)
+        })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position).expect("position should be inside the grid")
+    }
+}
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        self.get_mut(position)
+            .expect("position should be inside the grid")
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in s.lines() {
+            let len = line.chars().count();
+            match width {
+                Some(width) if width != len => {
+                    let end = line
+                        .char_indices()
+                        .nth(width)
+                        .map_or(&line[line.len()..], |(idx, _)| &line[idx..]);
+                    return Err(ParseError::at(s, end, format!("a row of {} cells", width)));
+                }
+                _ => width = Some(len),
+            }
+            cells.extend(line.chars());
+            height += 1;
+        }
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Grid;
+
+    #[test]
+    fn parse_and_display() {
+        let input = "467.\n...*\n..35";
+        let grid = input.parse::<Grid<char>>().unwrap();
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+        assert_eq!(grid.get((1, 3)), Some(&'*'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 4)), None);
+        assert_eq!(grid.to_string(), input);
+    }
+    #[test]
+    fn reject_ragged_rows() {
+        let err = "467.\n...*.\n..35".parse::<Grid<char>>().unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+        let err = "467.\n..\n..35".parse::<Grid<char>>().unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = "abc\ndef\nghi".parse::<Grid<char>>().unwrap();
+        assert_eq!(grid.neighbours4((0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        let around_corner: String = grid.neighbours8((2, 2)).map(|pos| grid[pos]).collect();
+        assert_eq!(around_corner, "efh");
+    }
+    #[test]
+    fn rows_and_columns() {
+        let grid = "abc\ndef".parse::<Grid<char>>().unwrap();
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, vec!["abc", "def"]);
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+        assert_eq!(grid.positions().count(), 6);
+    }
+}
@@ -0,0 +1,190 @@
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    marker::PhantomData,
+    path::Path,
+};
+
+use flate2::bufread::MultiGzDecoder;
+
+use crate::ParseError;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Why a streamed puzzle input could not be solved: reading it failed or one of its lines is malformed.
+#[derive(Debug)]
+pub enum InputError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl From<io::Error> for InputError {
+    fn from(err: io::Error) -> Self {
+        InputError::Io(err)
+    }
+}
+impl From<ParseError> for InputError {
+    fn from(err: ParseError) -> Self {
+        InputError::Parse(err)
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Io(err) => write!(f, "cannot read input: {}", err),
+            InputError::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Opens a puzzle input for streaming, `-` being stdin.
+///
+/// Gzip compressed inputs are recognised by their magic bytes and decompressed on the fly.
+pub fn open(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    if path == Path::new("-") {
+        decompressed(BufReader::new(io::stdin()))
+    } else {
+        decompressed(BufReader::new(File::open(path)?))
+    }
+}
+
+/// `reader` itself, or its decompressed content when it starts with a gzip header.
+pub fn decompressed<R>(mut reader: R) -> io::Result<Box<dyn BufRead>>
+where
+    R: BufRead + 'static,
+{
+    if reader.fill_buf()?.starts_with(&GZIP_MAGIC) {
+        Ok(Box::new(BufReader::new(MultiGzDecoder::new(reader))))
+    } else {
+        Ok(Box::new(reader))
+    }
+}
+
+/// Parses every line of `input`, errors pointing at their line in the whole input.
+pub fn parse_lines<'a, T, F>(
+    input: &'a str,
+    mut parse: F,
+) -> impl Iterator<Item = Result<T, ParseError>> + 'a
+where
+    F: FnMut(&str) -> Result<T, ParseError> + 'a,
+{
+    input
+        .lines()
+        .zip(1..)
+        .map(move |(line, number)| parse(line).map_err(|err| err.in_line(number)))
+}
+
+/// Streams the parsed lines of `reader`, see [`read_lines`].
+pub struct ReadLines<R, F, T> {
+    reader: R,
+    parse: F,
+    buffer: String,
+    number: usize,
+    parsed: PhantomData<fn() -> T>,
+}
+
+/// Parses the lines of `reader` one at a time.
+///
+/// A single line buffer is reused for the whole input, so memory stays bounded
+/// by the longest line whatever the size of the input.
+pub fn read_lines<R, T, F>(reader: R, parse: F) -> ReadLines<R, F, T>
+where
+    R: BufRead,
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    ReadLines {
+        reader,
+        parse,
+        buffer: String::new(),
+        number: 0,
+        parsed: PhantomData,
+    }
+}
+
+impl<R, F, T> Iterator for ReadLines<R, F, T>
+where
+    R: BufRead,
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    type Item = Result<T, InputError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buffer.clear();
+        match self.reader.read_line(&mut self.buffer) {
+            Ok(0) => return None,
+            Ok(_) => (),
+            Err(err) => return Some(Err(err.into())),
+        }
+        self.number += 1;
+        let line = self.buffer.strip_suffix('\n').unwrap_or(&self.buffer);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let number = self.number;
+        Some((self.parse)(line).map_err(|err| err.in_line(number).into()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{BufReader, Read, Write};
+
+    use flate2::{write::GzEncoder, Compression};
+
+    use super::{decompressed, parse_lines, read_lines, InputError};
+    use crate::ParseError;
+
+    fn number(line: &str) -> Result<u32, ParseError> {
+        line.parse()
+            .map_err(|_| ParseError::at(line, line, "a number"))
+    }
+
+    #[test]
+    fn stream_lines() {
+        let input = "1\r\n22\n333";
+        let numbers: Vec<u32> = read_lines(input.as_bytes(), number)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(numbers, vec![1, 22, 333]);
+        let borrowed: Vec<u32> = parse_lines(input, number)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(borrowed, numbers);
+    }
+    #[test]
+    fn number_streamed_errors() {
+        let mut lines = read_lines("1\n2\nx\n4".as_bytes(), number).skip(2);
+        match lines.next() {
+            Some(Err(InputError::Parse(err))) => assert_eq!((err.line, err.snippet.as_str()), (3, "x")),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        let err = parse_lines("1\n\n3", number).nth(1).unwrap().unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+    #[test]
+    fn report_invalid_utf8() {
+        let mut lines = read_lines(&b"1\n\xff\n"[..], number);
+        assert!(matches!(lines.next(), Some(Ok(1))));
+        assert!(matches!(lines.next(), Some(Err(InputError::Io(_)))));
+    }
+    #[test]
+    fn decompress_gzip() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"7\n8\n").unwrap();
+        let compressed = encoder.finish().unwrap();
+        let mut content = String::new();
+        decompressed(BufReader::new(std::io::Cursor::new(compressed)))
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!(content, "7\n8\n");
+        let mut plain = String::new();
+        decompressed(BufReader::new(std::io::Cursor::new(b"7\n8\n".to_vec())))
+            .unwrap()
+            .read_to_string(&mut plain)
+            .unwrap();
+        assert_eq!(plain, content);
+    }
+}
@@ -0,0 +1,35 @@
+use std::io::BufRead;
+
+mod error;
+mod grid;
+pub mod input;
+
+pub use error::ParseError;
+pub use grid::{Grid, Position};
+pub use input::InputError;
+
+/// A single puzzle day, answering both of its parts from the raw puzzle input.
+pub trait Solution {
+    fn part1(&self, input: &str) -> Result<String, ParseError>;
+    fn part2(&self, input: &str) -> Result<String, ParseError>;
+    /// Answers part 1 from a reader, days with line based inputs override it to stream them.
+    fn part1_from_reader(&self, input: &mut dyn BufRead) -> Result<String, InputError> {
+        let mut content = String::new();
+        input.read_to_string(&mut content)?;
+        Ok(self.part1(&content)?)
+    }
+    /// Answers part 2 from a reader, days with line based inputs override it to stream them.
+    fn part2_from_reader(&self, input: &mut dyn BufRead) -> Result<String, InputError> {
+        let mut content = String::new();
+        input.read_to_string(&mut content)?;
+        Ok(self.part2(&content)?)
+    }
+    /// Describes step by step how `value` is handled, `None` for days without such a report.
+    fn explain(&self, _input: &str, _value: u64) -> Option<Result<String, ParseError>> {
+        None
+    }
+    /// Every problem found checking `input` against the day's grammar, `None` for days without such a check.
+    fn validate(&self, _input: &str) -> Option<Vec<ParseError>> {
+        None
+    }
+}
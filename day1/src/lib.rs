@@ -0,0 +1,152 @@
+use std::{io::BufRead, str::FromStr, sync::OnceLock};
+
+use common::{
+    input::{parse_lines, read_lines},
+    InputError, ParseError, Solution,
+};
+
+mod tokenizer;
+
+pub use tokenizer::{Token, Tokenizer, Vocabulary};
+
+/// The first and last digit tokens of a line, the value being the first times ten plus the last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calibration {
+    pub value: u32,
+    pub first: Token,
+    pub last: Token,
+}
+impl Calibration {
+    pub fn parse_with(s: &str, tokenizer: &Tokenizer) -> Result<Self, ParseError> {
+        let first = tokenizer
+            .first(s)
+            .ok_or_else(|| ParseError::at(s, s, "a digit"))?;
+        let last = tokenizer
+            .last(s)
+            .ok_or_else(|| ParseError::at(s, s, "a digit"))?;
+        //words added in code may stand for any number, not just a digit
+        let value = first
+            .value
+            .checked_mul(10)
+            .and_then(|tens| tens.checked_add(last.value))
+            .ok_or_else(|| {
+                ParseError::at(s, &s[first.start..], "a calibration value within u32")
+            })?;
+        Ok(Calibration {
+            value,
+            first,
+            last,
+        })
+    }
+}
+impl FromStr for Calibration {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        static DIGITS: OnceLock<Tokenizer> = OnceLock::new();
+        Calibration::parse_with(s, DIGITS.get_or_init(|| Tokenizer::new(&Vocabulary::digits())))
+    }
+}
+
+/// Reads a line whose digits may also be spelled out in English.
+fn spelled_calibration(line: &str) -> Result<Calibration, ParseError> {
+    static ENGLISH: OnceLock<Tokenizer> = OnceLock::new();
+    Calibration::parse_with(line, ENGLISH.get_or_init(|| Tokenizer::new(&Vocabulary::english())))
+}
+fn sum_values<E>(
+    mut calibrations: impl Iterator<Item = Result<Calibration, E>>,
+) -> Result<u64, E> {
+    calibrations.try_fold(0, |val, cal| Ok(u64::from(cal?.value) + val))
+}
+fn calibration_total(input: &str) -> Result<u64, ParseError> {
+    sum_values(parse_lines(input, Calibration::from_str))
+}
+fn spelled_calibration_total(input: &str) -> Result<u64, ParseError> {
+    sum_values(parse_lines(input, spelled_calibration))
+}
+/// Sums the calibration values of `input`, reading digits with the words of `vocabulary`.
+pub fn calibration_sum<R: BufRead>(input: R, vocabulary: &Vocabulary) -> Result<u64, InputError> {
+    let tokenizer = Tokenizer::new(vocabulary);
+    sum_values(read_lines(input, |line| Calibration::parse_with(line, &tokenizer)))
+}
+
+pub struct Day1;
+impl Solution for Day1 {
+    fn part1(&self, input: &str) -> Result<String, ParseError> {
+        Ok(calibration_total(input)?.to_string())
+    }
+    fn part2(&self, input: &str) -> Result<String, ParseError> {
+        Ok(spelled_calibration_total(input)?.to_string())
+    }
+    fn part1_from_reader(&self, input: &mut dyn BufRead) -> Result<String, InputError> {
+        Ok(sum_values(read_lines(input, Calibration::from_str))?.to_string())
+    }
+    fn part2_from_reader(&self, input: &mut dyn BufRead) -> Result<String, InputError> {
+        Ok(sum_values(read_lines(input, spelled_calibration))?.to_string())
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::{calibration_sum, calibration_total, spelled_calibration, Calibration, Day1, Vocabulary};
+    use common::{InputError, Solution};
+    #[test]
+    fn test_calibration_part1() {
+        let input_string = r"1abc2
+                             pqr3stu8vwx
+                             a1b2c3d4e5f
+                             treb7uchet";
+        let value: u32 = input_string
+            .lines()
+            .map(|line| line.parse::<Calibration>().unwrap().value)
+            .sum();
+        assert_eq!(value, 142);
+    }
+    #[test]
+    fn test_calibration_part2() {
+        let input_string = r"two1nine
+                            eightwothree
+                            abcone2threexyz
+                            xtwone3four
+                            4nineeightseven2
+                            zoneight234
+                            7pqrstsixteen";
+        let value: u32 = input_string
+            .lines()
+            .map(|line| spelled_calibration(line.trim()).unwrap().value)
+            .sum();
+        assert_eq!(value, 281);
+    }
+    #[test]
+    fn report_line_without_digit() {
+        let err = calibration_total("1abc2\nabc").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.snippet, "abc");
+    }
+    #[test]
+    fn report_token_positions() {
+        let calibration = spelled_calibration("xtwone3four").unwrap();
+        assert_eq!(calibration.value, 24);
+        assert_eq!((calibration.first.start, calibration.first.end), (1, 4));
+        assert_eq!((calibration.last.start, calibration.last.end), (7, 11));
+    }
+    #[test]
+    fn stream_calibrations() {
+        let mut input = "two1nine\neightwothree\nabcone2threexyz".as_bytes();
+        assert_eq!(Day1.part2_from_reader(&mut input).unwrap(), "125");
+        let err = Day1.part1_from_reader(&mut "1abc2\nabc".as_bytes()).unwrap_err();
+        assert!(matches!(err, InputError::Parse(err) if err.line == 2));
+    }
+    #[test]
+    fn sum_with_vocabulary() {
+        let input = "xzweiundvierzig\nachtzehn3\n";
+        assert_eq!(calibration_sum(input.as_bytes(), &Vocabulary::german()).unwrap(), 24 + 83);
+        let vocabulary = "un 1\ndeux 2".parse::<Vocabulary>().unwrap();
+        let err = calibration_sum("undeux\n3".as_bytes(), &vocabulary).unwrap_err();
+        assert!(matches!(err, InputError::Parse(err) if err.line == 2));
+    }
+    #[test]
+    fn report_overflowing_values() {
+        let vocabulary = Vocabulary::digits().word("big", u32::MAX);
+        let err = calibration_sum("1\nxbig2\n".as_bytes(), &vocabulary).unwrap_err();
+        assert!(matches!(err, InputError::Parse(err) if (err.line, err.column) == (2, 2)));
+    }
+}
@@ -0,0 +1,339 @@
+use std::{collections::VecDeque, path::Path, str::FromStr};
+
+use common::{InputError, ParseError};
+
+const ALPHABET: usize = 256;
+const MISSING: usize = usize::MAX;
+
+/// Words standing for a digit value, numeric or spelled out.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+}
+
+impl Vocabulary {
+    /// The numeric digits `0` to `9`.
+    pub fn digits() -> Self {
+        (0..10).fold(Vocabulary::default(), |vocabulary, digit| {
+            vocabulary.word(digit.to_string(), digit)
+        })
+    }
+    /// The numeric digits and their English names, `zero` to `nine`.
+    pub fn english() -> Self {
+        Vocabulary::spelled([
+            "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ])
+    }
+    /// The numeric digits and their German names, `null` to `neun`.
+    pub fn german() -> Self {
+        Vocabulary::spelled([
+            "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+        ])
+    }
+    /// The numeric digits and their French names, `zéro` to `neuf`.
+    pub fn french() -> Self {
+        Vocabulary::spelled([
+            "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+        ])
+    }
+    /// The numeric digits along with `names`, the name of digit `n` at index `n`.
+    pub fn spelled(names: [&str; 10]) -> Self {
+        names
+            .iter()
+            .zip(0..)
+            .fold(Vocabulary::digits(), |vocabulary, (name, digit)| {
+                vocabulary.word(*name, digit)
+            })
+    }
+    /// Reads a vocabulary file, see the [`FromStr`] implementation for its format.
+    pub fn load(path: &Path) -> Result<Self, InputError> {
+        Ok(std::fs::read_to_string(path)?.parse()?)
+    }
+    /// Adds `word` standing for `value`, panics when `word` is empty.
+    pub fn word(mut self, word: impl Into<String>, value: u32) -> Self {
+        let word = word.into();
+        assert!(!word.is_empty(), "vocabulary words cannot be empty");
+        self.words.push((word, value));
+        self
+    }
+    pub fn words(&self) -> &[(String, u32)] {
+        &self.words
+    }
+}
+
+/// One `word value` pair per line, blank lines and lines starting with `#` are skipped.
+///
+/// Values are single digits, `0` to `9`. Numeric digits are not implied, list `1 1` and so on
+/// to match them too.
+impl FromStr for Vocabulary {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut vocabulary = Vocabulary::default();
+        for (line, number) in s.lines().zip(1..) {
+            let content = line.trim_start();
+            if content.is_empty() || content.starts_with('#') {
+                continue;
+            }
+            let (word, value) = vocabulary_entry(line, content).map_err(|err| err.in_line(number))?;
+            vocabulary = vocabulary.word(word, value);
+        }
+        Ok(vocabulary)
+    }
+}
+fn vocabulary_entry<'a>(line: &'a str, content: &'a str) -> Result<(&'a str, u32), ParseError> {
+    let mut fields = content.split_whitespace();
+    let word = fields.next().unwrap_or(content);
+    let value = fields
+        .next()
+        .ok_or_else(|| ParseError::at(line, &content[word.len()..], "a value"))?;
+    let value = match value.parse::<u32>() {
+        Ok(digit) if digit <= 9 => digit,
+        Ok(_) => return Err(ParseError::at(line, value, "a value from 0 to 9")),
+        Err(_) => return Err(ParseError::at(line, value, "a value")),
+    };
+    match fields.next() {
+        Some(extra) => Err(ParseError::at(line, extra, "end of line")),
+        None => Ok((word, value)),
+    }
+}
+
+/// A vocabulary word found in a line, `start..end` being its byte range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub value: u32,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Aho-Corasick automaton over bytes, with every transition precomputed.
+struct Automaton {
+    delta: Vec<[usize; ALPHABET]>,
+    //words ending in each state, its own and the ones of its suffixes
+    outputs: Vec<Vec<usize>>,
+    lengths: Vec<usize>,
+    longest: usize,
+}
+
+impl Automaton {
+    fn new(words: &[Vec<u8>]) -> Self {
+        let mut delta = vec![[MISSING; ALPHABET]];
+        let mut outputs = vec![Vec::new()];
+        for (idx, word) in words.iter().enumerate() {
+            let mut state = 0;
+            for byte in word {
+                if delta[state][*byte as usize] == MISSING {
+                    delta[state][*byte as usize] = delta.len();
+                    delta.push([MISSING; ALPHABET]);
+                    outputs.push(Vec::new());
+                }
+                state = delta[state][*byte as usize];
+            }
+            outputs[state].push(idx);
+        }
+        let mut fail = vec![0; delta.len()];
+        let mut queue = VecDeque::new();
+        for next in delta[0].iter_mut() {
+            match *next {
+                MISSING => *next = 0,
+                next => queue.push_back(next),
+            }
+        }
+        //breadth first, so the fallback of a state is complete before the state itself
+        while let Some(state) = queue.pop_front() {
+            let inherited = outputs[fail[state]].clone();
+            outputs[state].extend(inherited);
+            let fallbacks = delta[fail[state]];
+            for (next, fallback) in delta[state].iter_mut().zip(fallbacks) {
+                match *next {
+                    MISSING => *next = fallback,
+                    next => {
+                        fail[next] = fallback;
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+        let lengths: Vec<usize> = words.iter().map(Vec::len).collect();
+        Automaton {
+            delta,
+            outputs,
+            longest: lengths.iter().copied().max().unwrap_or(0),
+            lengths,
+        }
+    }
+    /// Every match as `(word, start)`, in the order their last byte is reached.
+    fn matches<'a>(&'a self, bytes: &'a [u8]) -> impl Iterator<Item = (usize, usize)> + 'a {
+        bytes
+            .iter()
+            .enumerate()
+            .scan(0, move |state, (pos, byte)| {
+                *state = self.delta[*state][*byte as usize];
+                Some((pos, *state))
+            })
+            .flat_map(move |(pos, state)| {
+                self.outputs[state]
+                    .iter()
+                    .map(move |word| (*word, pos + 1 - self.lengths[*word]))
+            })
+    }
+    /// The earliest starting match as `(word, start)`, the longest one on ties.
+    ///
+    /// Stops as soon as no word could start before the best match found.
+    fn leftmost(&self, bytes: impl Iterator<Item = u8>) -> Option<(usize, usize)> {
+        let mut state = 0;
+        let mut best: Option<(usize, usize)> = None;
+        for (pos, byte) in bytes.enumerate() {
+            if matches!(best, Some((_, start)) if pos >= start + self.longest) {
+                break;
+            }
+            state = self.delta[state][byte as usize];
+            for word in &self.outputs[state] {
+                let start = pos + 1 - self.lengths[*word];
+                best = match best {
+                    Some((best_word, best_start))
+                        if best_start < start
+                            || (best_start == start
+                                && self.lengths[best_word] >= self.lengths[*word]) =>
+                    {
+                        Some((best_word, best_start))
+                    }
+                    _ => Some((*word, start)),
+                };
+            }
+        }
+        best
+    }
+}
+
+/// Finds the first and last vocabulary words of a line, overlapping ones included.
+///
+/// "twone" starts with `two` and ends with `one`: the line is scanned forward
+/// for the first word and backward, with the words reversed, for the last one.
+pub struct Tokenizer {
+    values: Vec<u32>,
+    forward: Automaton,
+    backward: Automaton,
+}
+
+impl Tokenizer {
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        let words: Vec<Vec<u8>> = vocabulary
+            .words()
+            .iter()
+            .map(|(word, _)| word.as_bytes().to_vec())
+            .collect();
+        let reversed: Vec<Vec<u8>> = words
+            .iter()
+            .map(|word| word.iter().rev().copied().collect())
+            .collect();
+        Tokenizer {
+            values: vocabulary.words().iter().map(|(_, value)| *value).collect(),
+            forward: Automaton::new(&words),
+            backward: Automaton::new(&reversed),
+        }
+    }
+    /// The word starting first in `line`, the longest one when several start there.
+    pub fn first(&self, line: &str) -> Option<Token> {
+        let (word, start) = self.forward.leftmost(line.bytes())?;
+        Some(self.token(word, start))
+    }
+    /// The word ending last in `line`, the longest one when several end there.
+    pub fn last(&self, line: &str) -> Option<Token> {
+        let (word, from_end) = self.backward.leftmost(line.bytes().rev())?;
+        Some(self.token(word, line.len() - from_end - self.forward.lengths[word]))
+    }
+    /// Every word of `line`, overlapping ones included, ordered by where they end.
+    pub fn tokens(&self, line: &str) -> Vec<Token> {
+        self.forward
+            .matches(line.as_bytes())
+            .map(|(word, start)| self.token(word, start))
+            .collect()
+    }
+    fn token(&self, word: usize, start: usize) -> Token {
+        Token {
+            value: self.values[word],
+            start,
+            end: start + self.forward.lengths[word],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Token, Tokenizer, Vocabulary};
+    use std::str::FromStr;
+
+    fn span(token: Option<Token>) -> Option<(u32, usize, usize)> {
+        token.map(|token| (token.value, token.start, token.end))
+    }
+
+    #[test]
+    fn overlapping_words() {
+        let tokenizer = Tokenizer::new(&Vocabulary::english());
+        assert_eq!(span(tokenizer.first("xtwone3four")), Some((2, 1, 4)));
+        assert_eq!(span(tokenizer.last("xtwone3four")), Some((4, 7, 11)));
+        assert_eq!(span(tokenizer.last("zoneight")), Some((8, 3, 8)));
+        assert_eq!(span(tokenizer.first("eightwothree")), Some((8, 0, 5)));
+        assert_eq!(span(tokenizer.first("no digit here")), None);
+        let values: Vec<u32> = tokenizer
+            .tokens("oneightwo")
+            .iter()
+            .map(|token| token.value)
+            .collect();
+        assert_eq!(values, vec![1, 8, 2]);
+    }
+    #[test]
+    fn numeric_only() {
+        let tokenizer = Tokenizer::new(&Vocabulary::digits());
+        assert_eq!(span(tokenizer.first("two1nine")), Some((1, 3, 4)));
+        assert_eq!(span(tokenizer.last("two1nine")), Some((1, 3, 4)));
+    }
+    #[test]
+    fn custom_words_prefer_longest() {
+        let vocabulary = Vocabulary::english()
+            .word("ten", 10)
+            .word("eleven", 11)
+            .word("seventeen", 17);
+        let tokenizer = Tokenizer::new(&vocabulary);
+        //"seven" and "seventeen" both start the line, "seventeen" is longer
+        assert_eq!(span(tokenizer.first("seventeen2")), Some((17, 0, 9)));
+        assert_eq!(span(tokenizer.last("2eleven")), Some((11, 1, 7)));
+        assert_eq!(span(tokenizer.first("tenine")), Some((10, 0, 3)));
+        assert_eq!(span(tokenizer.last("tenine")), Some((9, 2, 6)));
+        let german = Vocabulary::digits().word("zwölf", 12).word("drei", 3);
+        let tokenizer = Tokenizer::new(&german);
+        assert_eq!(span(tokenizer.first("xzwölfdrei")), Some((12, 1, 7)));
+        assert_eq!(span(tokenizer.last("xzwölfdrei")), Some((3, 7, 11)));
+    }
+    #[test]
+    fn parse_vocabulary() {
+        let input = "# Dutch\n\neen 1\ntwee 2\n  drie\t3\n";
+        let vocabulary = Vocabulary::from_str(input).unwrap();
+        assert_eq!(
+            vocabulary,
+            Vocabulary::default().word("een", 1).word("twee", 2).word("drie", 3)
+        );
+        let tokenizer = Tokenizer::new(&vocabulary);
+        assert_eq!(span(tokenizer.first("xtweeen")), Some((2, 1, 5)));
+        assert_eq!(span(tokenizer.last("xtweeen")), Some((1, 4, 7)));
+        let err = Vocabulary::from_str("een 1\ntwee two").unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (2, 6, "a value"));
+        let err = Vocabulary::from_str("een").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 4));
+        let err = Vocabulary::from_str("een 1 2").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 7));
+        let err = Vocabulary::from_str("een 1\ntien 10").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+        assert_eq!(err.expected, "a value from 0 to 9");
+    }
+    #[test]
+    fn builtin_languages() {
+        let tokenizer = Tokenizer::new(&Vocabulary::german());
+        assert_eq!(span(tokenizer.first("xfünfzwei")), Some((5, 1, 6)));
+        assert_eq!(span(tokenizer.last("xfünfzwei")), Some((2, 6, 10)));
+        let tokenizer = Tokenizer::new(&Vocabulary::french());
+        assert_eq!(span(tokenizer.first("zérouneuf")), Some((0, 0, 5)));
+        assert_eq!(span(tokenizer.last("zérouneuf")), Some((9, 6, 10)));
+    }
+}
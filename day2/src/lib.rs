@@ -0,0 +1,449 @@
+use common::{
+    input::{parse_lines, read_lines},
+    InputError, ParseError, Solution,
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, alpha1},
+    combinator::{all_consuming, cut},
+    error::{context, VerboseError, VerboseErrorKind},
+    multi::separated_list1,
+    sequence::{pair, preceded, separated_pair, terminated},
+    IResult,
+};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+/// The colours the puzzle asks about.
+pub const CUBES: [&str; 3] = ["red", "green", "blue"];
+
+/// Cubes shown in a single draw, by colour.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Subset {
+    counts: BTreeMap<String, u32>,
+}
+impl Subset {
+    pub fn new<'a>(counts: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        Subset {
+            counts: counts
+                .into_iter()
+                .map(|(colour, count)| (colour.to_string(), count))
+                .collect(),
+        }
+    }
+    /// Cubes of `colour` in the draw, 0 when it was not mentioned.
+    pub fn count(&self, colour: &str) -> u32 {
+        self.counts.get(colour).copied().unwrap_or(0)
+    }
+    pub fn colours(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.counts
+            .iter()
+            .map(|(colour, count)| (colour.as_str(), *count))
+    }
+}
+/// The grammar below names what it expected wherever it fails.
+type Parsed<'a, T> = IResult<&'a str, T, VerboseError<&'a str>>;
+/// The `(count, colour)` items of a draw as written.
+type Cubes<'a> = Vec<(u32, &'a str)>;
+
+fn game_id(input: &str) -> Parsed<'_, u32> {
+    context("Game <id>", preceded(tag("Game "), complete::u32))(input)
+}
+fn cubes(input: &str) -> Parsed<'_, (u32, &str)> {
+    context(
+        "<count> <colour>",
+        separated_pair(complete::u32, tag(" "), alpha1),
+    )(input)
+}
+/// `<count> <colour>` items separated by `, `.
+fn draw(input: &str) -> Parsed<'_, Cubes<'_>> {
+    separated_list1(tag(", "), cut(cubes))(input)
+}
+/// A whole `Game <id>: <draw>; <draw>...` line.
+fn game(input: &str) -> Parsed<'_, (u32, Vec<Cubes<'_>>)> {
+    context(
+        "', ', '; ' or end of line",
+        all_consuming(pair(
+            terminated(game_id, context("': '", tag(": "))),
+            separated_list1(tag("; "), cut(draw)),
+        )),
+    )(input)
+}
+/// Points at where the grammar failed, expecting what its innermost context names.
+fn grammar_error(source: &str, err: nom::Err<VerboseError<&str>>) -> ParseError {
+    let end = &source[source.len()..];
+    match err {
+        nom::Err::Error(err) | nom::Err::Failure(err) => {
+            let expected = err.errors.iter().find_map(|(_, kind)| match kind {
+                VerboseErrorKind::Context(expected) => Some(*expected),
+                _ => None,
+            });
+            let at = err.errors.first().map_or(end, |(input, _)| *input);
+            ParseError::at(source, at, expected.unwrap_or("a game"))
+        }
+        nom::Err::Incomplete(_) => ParseError::at(source, end, "a game"),
+    }
+}
+/// The draw made of `cubes`, slices of `source`, failing when a colour comes twice.
+fn subset(source: &str, cubes: &[(u32, &str)]) -> Result<Subset, ParseError> {
+    let mut counts = BTreeMap::new();
+    for (count, colour) in cubes {
+        if counts.insert(colour.to_string(), *count).is_some() {
+            return Err(ParseError::at(source, colour, "each colour once per draw"));
+        }
+    }
+    Ok(Subset { counts })
+}
+/// A single draw such as `3 blue, 4 red`.
+impl FromStr for Subset {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, cubes) = context("', ' or end of line", all_consuming(draw))(s)
+            .map_err(|err| grammar_error(s, err))?;
+        subset(s, &cubes)
+    }
+}
+/// The draw as `<count> <colour>` items, colours in alphabetical order.
+impl fmt::Display for Subset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, (colour, count)) in self.colours().enumerate() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} {}", count, colour)?;
+        }
+        Ok(())
+    }
+}
+
+/// How many cubes of each colour a bag holds, colours it does not list have none.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bag {
+    limits: BTreeMap<String, u32>,
+}
+impl Bag {
+    pub fn new<'a>(limits: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        Bag {
+            limits: limits
+                .into_iter()
+                .map(|(colour, limit)| (colour.to_string(), limit))
+                .collect(),
+        }
+    }
+    pub fn limit(&self, colour: &str) -> u32 {
+        self.limits.get(colour).copied().unwrap_or(0)
+    }
+    /// Whether this bag holds at least as many cubes of every colour as `other`.
+    pub fn contains(&self, other: &Bag) -> bool {
+        other
+            .limits
+            .iter()
+            .all(|(colour, limit)| self.limit(colour) >= *limit)
+    }
+    /// Product of the limits of `colours`, `None` when it does not fit in u64.
+    pub fn power(&self, colours: &[&str]) -> Option<u64> {
+        colours.iter().try_fold(1u64, |power, colour| {
+            power.checked_mul(u64::from(self.limit(colour)))
+        })
+    }
+    pub fn is_game_possible(&self, game: &Game) -> bool {
+        game.impossible_draw(self).is_none()
+    }
+}
+
+/// The first draw of a game showing more cubes of a colour than the bag holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImpossibleDraw {
+    /// Index of the draw in the game, from 0.
+    pub draw: usize,
+    pub colour: String,
+    pub count: u32,
+    pub limit: u32,
+}
+
+fn solve<E>(mut games: impl Iterator<Item = Result<Game, E>>) -> Result<u64, E> {
+    let bag = Bag::new(CUBES.into_iter().zip([12, 13, 14]));
+    games.try_fold(0, |acc, game| {
+        let game = game?;
+        Ok(if bag.is_game_possible(&game) {
+            acc + u64::from(game.id)
+        } else {
+            acc
+        })
+    })
+}
+/// The power of the minimum bag of the game on `line`.
+fn game_power(line: &str) -> Result<u64, ParseError> {
+    line.parse::<Game>()?
+        .minimum_bag()
+        .power(&CUBES)
+        .ok_or_else(|| ParseError::at(line, line, "a game whose power fits in u64"))
+}
+fn solve_part2<E>(mut powers: impl Iterator<Item = Result<u64, E>>) -> Result<u64, E> {
+    powers.try_fold(0, |acc, power| Ok(acc + power?))
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub subsets: Vec<Subset>,
+}
+impl Game {
+    /// The smallest bag every draw of the game fits in.
+    pub fn minimum_bag(&self) -> Bag {
+        let mut limits: BTreeMap<String, u32> = BTreeMap::new();
+        for (colour, count) in self.subsets.iter().flat_map(Subset::colours) {
+            let limit = limits.entry(colour.to_string()).or_default();
+            *limit = (*limit).max(count);
+        }
+        Bag { limits }
+    }
+    /// Whether the game is possible with each of `bags`, in order.
+    pub fn possible_with(&self, bags: &[Bag]) -> Vec<bool> {
+        let minimum = self.minimum_bag();
+        bags.iter().map(|bag| bag.contains(&minimum)).collect()
+    }
+    /// The first draw that could not come out of `bag`, if any.
+    pub fn impossible_draw(&self, bag: &Bag) -> Option<ImpossibleDraw> {
+        self.subsets
+            .iter()
+            .enumerate()
+            .find_map(|(draw, subset)| {
+                subset
+                    .colours()
+                    .find(|(colour, count)| *count > bag.limit(colour))
+                    .map(|(colour, count)| ImpossibleDraw {
+                        draw,
+                        colour: colour.to_string(),
+                        count,
+                        limit: bag.limit(colour),
+                    })
+            })
+    }
+    /// Parses a game, warning about the first place it breaks the grammar of [`FromStr`] and
+    /// keeping the well-formed cubes of its `;` and `,` separated items.
+    ///
+    /// Only a broken `Game <id>: ` header is an error.
+    pub fn parse_lenient(s: &str) -> Result<(Game, Vec<ParseError>), ParseError> {
+        let warning = match s.parse::<Game>() {
+            Ok(game) => return Ok((game, Vec::new())),
+            Err(warning) => warning,
+        };
+        let (rest, id) = terminated(game_id, context("': '", tag(": ")))(s)
+            .map_err(|err| grammar_error(s, err))?;
+        let subsets = rest
+            .split(';')
+            .map(|items| {
+                let mut counts = BTreeMap::new();
+                for item in items.split(',') {
+                    if let Ok((_, (count, colour))) = all_consuming(cubes)(item.trim()) {
+                        counts.entry(colour.to_string()).or_insert(count);
+                    }
+                }
+                Subset { counts }
+            })
+            .collect();
+        Ok((Game { id, subsets }, vec![warning]))
+    }
+}
+/// A `Game <id>: <draw>; <draw>...` line, cubes and draws separated by `, ` and `; `.
+impl FromStr for Game {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, (id, draws)) = game(s).map_err(|err| grammar_error(s, err))?;
+        let subsets = draws
+            .iter()
+            .map(|cubes| subset(s, cubes))
+            .collect::<Result<_, _>>()?;
+        Ok(Game { id, subsets })
+    }
+}
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {}:", self.id)?;
+        for (idx, subset) in self.subsets.iter().enumerate() {
+            let separator = if idx > 0 { ";" } else { "" };
+            write!(f, "{} {}", separator, subset)?;
+        }
+        Ok(())
+    }
+}
+
+/// Every game of `input` that has a valid header, along with the warnings of all lines.
+pub fn parse_lenient_games(input: &str) -> (Vec<Game>, Vec<ParseError>) {
+    let mut games = Vec::new();
+    let mut warnings = Vec::new();
+    for (line, number) in input.lines().zip(1..) {
+        match Game::parse_lenient(line) {
+            Ok((game, game_warnings)) => {
+                games.push(game);
+                warnings.extend(game_warnings.into_iter().map(|err| err.in_line(number)));
+            }
+            Err(err) => warnings.push(err.in_line(number)),
+        }
+    }
+    (games, warnings)
+}
+
+pub struct Day2;
+impl Solution for Day2 {
+    fn part1(&self, input: &str) -> Result<String, ParseError> {
+        Ok(solve(parse_lines(input, Game::from_str))?.to_string())
+    }
+    fn part2(&self, input: &str) -> Result<String, ParseError> {
+        Ok(solve_part2(parse_lines(input, game_power))?.to_string())
+    }
+    fn part1_from_reader(&self, input: &mut dyn BufRead) -> Result<String, InputError> {
+        Ok(solve(read_lines(input, Game::from_str))?.to_string())
+    }
+    fn part2_from_reader(&self, input: &mut dyn BufRead) -> Result<String, InputError> {
+        Ok(solve_part2(read_lines(input, game_power))?.to_string())
+    }
+    fn validate(&self, input: &str) -> Option<Vec<ParseError>> {
+        Some(parse_lenient_games(input).1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Bag, Game, ImpossibleDraw, Subset, CUBES, game_power, parse_lenient_games, solve, solve_part2};
+    use common::input::{parse_lines, read_lines};
+    use std::str::FromStr;
+    #[test]
+    fn parse_a_subset() {
+        let input1 = "3 blue, 4 red";
+        let input2 = "1 red, 20 green, 6 blue";
+        let input3 = "2 green";
+        assert_eq!(input1.parse::<Subset>().unwrap(), Subset::new([("red", 4), ("blue", 3)])); //<Subset as FromStr>::from_str("foo")
+        assert_eq!(input2.parse::<Subset>().unwrap(), Subset::new([("red", 1), ("green", 20), ("blue", 6)]));
+        assert_eq!(input3.parse::<Subset>().unwrap(), Subset::new([("green", 2)]));
+        assert_eq!(input3.parse::<Subset>().unwrap().count("red"), 0);
+        assert!(" 3 blue".parse::<Subset>().is_err());
+    }
+    #[test]
+    fn parse_a_game() {
+        let game_input = "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue";
+        let game = game_input.parse::<Game>().unwrap();
+        assert_eq!(game.subsets.len(), 3);
+        assert_eq!(game.id, 2);
+        assert_eq!(game.minimum_bag().power(&CUBES), Some(12))
+    }
+    #[test]
+    fn test_game_against_bag() {
+        let bag = Bag::new([("red", 12), ("green", 13), ("blue", 14)]);
+        let game = "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue"
+            .parse::<Game>()
+            .unwrap();
+        let game2 = "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red"
+            .parse::<Game>()
+            .unwrap();
+        assert!(bag.is_game_possible(&game));
+        assert!(!bag.is_game_possible(&game2));
+        assert_eq!(
+            game2.impossible_draw(&bag),
+            Some(ImpossibleDraw {
+                draw: 2,
+                colour: "blue".to_string(),
+                count: 15,
+                limit: 14
+            })
+        );
+    }
+    #[test]
+    fn analyse_extra_colours() {
+        let game = "Game 7: 2 yellow, 1 red; 3 green, 5 yellow".parse::<Game>().unwrap();
+        assert_eq!(
+            game.minimum_bag(),
+            Bag::new([("green", 3), ("red", 1), ("yellow", 5)])
+        );
+        let bags = [
+            Bag::new([("red", 12), ("green", 13), ("blue", 14)]),
+            Bag::new([("red", 1), ("green", 3), ("yellow", 5)]),
+            Bag::new([("red", 1), ("green", 3), ("yellow", 4)]),
+        ];
+        assert_eq!(game.possible_with(&bags), vec![false, true, false]);
+        let draw = game.impossible_draw(&bags[0]).unwrap();
+        assert_eq!((draw.draw, draw.colour.as_str()), (0, "yellow"));
+        assert_eq!(game.impossible_draw(&bags[2]).unwrap().draw, 1);
+    }
+    #[test]
+    fn solve_an_example() {
+        let buff = r"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        let result = solve(parse_lines(buff, Game::from_str)).unwrap();
+        let result2 = solve_part2(read_lines(buff.as_bytes(), game_power)).unwrap();
+        assert_eq!(result, 8);
+        assert_eq!(result2, 2286);
+    }
+    #[test]
+    fn power_of_large_counts() {
+        let line = "Game 1: 5000 red, 5000 green, 5000 blue";
+        assert_eq!(game_power(line), Ok(125_000_000_000));
+        let line = "Game 1: 4294967295 red, 4294967295 green; 4294967295 blue";
+        let bag = line.parse::<Game>().unwrap().minimum_bag();
+        assert_eq!(bag.power(&["red", "green"]), Some(18446744065119617025));
+        let err = game_power(line).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.expected, "a game whose power fits in u64");
+    }
+    #[test]
+    fn report_malformed_game() {
+        let buff = "Game 1: 3 blue\nGame 2 1 blue";
+        let err = solve(parse_lines(buff, Game::from_str)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 7));
+        assert_eq!(err.expected, "': '");
+        let err = "Gaem 3: 1 red".parse::<Game>().unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.expected, "Game <id>");
+    }
+    #[test]
+    fn reject_malformed_draws() {
+        let cases = [
+            ("Game 1: 3 blue, x red", 17, "<count> <colour>"),
+            ("Game 1: 3 blue 4 red", 15, "', ', '; ' or end of line"),
+            ("Game 1: 3blue", 10, "<count> <colour>"),
+            ("Game 1: 3 blue, 2 blue", 19, "each colour once per draw"),
+            ("Game 1: 3 blue;; 1 red", 15, "', ', '; ' or end of line"),
+            ("Game 1: 3 blue; ; 1 red", 17, "<count> <colour>"),
+            ("Game 1:3 blue ,  4 red;5 green", 7, "': '"),
+            ("Game 1: 3 blue ,  4 red", 15, "', ', '; ' or end of line"),
+            ("Game 1: 3 blue,  4 red", 17, "<count> <colour>"),
+            ("Game 1: 3 blue;5 green", 15, "', ', '; ' or end of line"),
+            ("Game 1: 3 blue ", 15, "', ', '; ' or end of line"),
+            ("Game  1: 3 blue", 6, "Game <id>"),
+            ("Game 1: 99999999999 red", 9, "<count> <colour>"),
+        ];
+        for (line, column, expected) in cases {
+            let err = line.parse::<Game>().unwrap_err();
+            assert_eq!((err.column, err.expected.as_str()), (column, expected), "{}", line);
+        }
+        assert!("3 blue; 4 red".parse::<Subset>().is_err());
+    }
+    #[test]
+    fn warn_in_lenient_mode() {
+        let input = "Game 1: 3 blue, x red; 2 green\nGaem 2: 1 red\nGame 3: 4 yellow";
+        let (games, warnings) = parse_lenient_games(input);
+        let ids: Vec<u32> = games.iter().map(|game| game.id).collect();
+        assert_eq!(ids, vec![1, 3]);
+        assert_eq!(games[0].subsets[0], Subset::new([("blue", 3)]));
+        let positions: Vec<(usize, usize)> =
+            warnings.iter().map(|err| (err.line, err.column)).collect();
+        assert_eq!(positions, vec![(1, 17), (2, 1)]);
+        let (game, warnings) = Game::parse_lenient("Game 4: 3 blue ,  4 red;5 green").unwrap();
+        assert_eq!(
+            game.subsets,
+            vec![
+                Subset::new([("blue", 3), ("red", 4)]),
+                Subset::new([("green", 5)])
+            ]
+        );
+        assert_eq!(warnings[0].column, 15);
+        let (_, warnings) = Game::parse_lenient("Game 5: 1 red; 2 blue").unwrap();
+        assert!(warnings.is_empty());
+    }
+}
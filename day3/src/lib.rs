@@ -0,0 +1,262 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    str::FromStr,
+};
+
+use common::{Grid, ParseError, Position, Solution};
+
+/// A run of digits on a single row, `end` is the column just past its last digit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartNumber {
+    pub value: u32,
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Any cell that is neither a digit nor a `.`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub kind: char,
+    pub position: Position,
+}
+
+pub struct Schematic {
+    grid: Grid<char>,
+    numbers: Vec<PartNumber>,
+    symbols: Vec<Symbol>,
+    //index into `numbers` for every cell covered by a digit
+    number_at: HashMap<Position, usize>,
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+impl FromStr for Schematic {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = s.parse::<Grid<char>>()?;
+        let mut numbers = Vec::new();
+        let mut symbols = Vec::new();
+        let mut number_at = HashMap::new();
+        for (row, line) in grid.rows().enumerate() {
+            let mut column = 0;
+            while column < line.len() {
+                let c = line[column];
+                if !c.is_ascii_digit() {
+                    if is_symbol(c) {
+                        symbols.push(Symbol {
+                            kind: c,
+                            position: (row, column),
+                        });
+                    }
+                    column += 1;
+                    continue;
+                }
+                let start = column;
+                let mut value: u32 = 0;
+                while let Some(digit) = line.get(column).and_then(|c| c.to_digit(10)) {
+                    value = value
+                        .checked_mul(10)
+                        .and_then(|value| value.checked_add(digit))
+                        .ok_or_else(|| ParseError {
+                            line: row + 1,
+                            column: start + 1,
+                            snippet: line[start..].iter().collect(),
+                            expected: "a number that fits in u32".to_string(),
+                        })?;
+                    number_at.insert((row, column), numbers.len());
+                    column += 1;
+                }
+                numbers.push(PartNumber {
+                    value,
+                    row,
+                    start,
+                    end: column,
+                });
+            }
+        }
+        Ok(Schematic {
+            grid,
+            numbers,
+            symbols,
+            number_at,
+        })
+    }
+}
+
+impl Schematic {
+    pub fn numbers(&self) -> &[PartNumber] {
+        &self.numbers
+    }
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+    /// Every number touching `symbol`, including diagonally, each listed once.
+    pub fn numbers_around(&self, symbol: &Symbol) -> Vec<&PartNumber> {
+        self.numbers_touching([symbol])
+    }
+    /// Numbers touching at least one symbol of any kind.
+    pub fn part_numbers(&self) -> Vec<&PartNumber> {
+        self.numbers_touching(&self.symbols)
+    }
+    /// Numbers touching at least one symbol of the given kind.
+    pub fn numbers_adjacent_to(&self, kind: char) -> Vec<&PartNumber> {
+        self.numbers_touching(self.symbols.iter().filter(|symbol| symbol.kind == kind))
+    }
+    /// Symbols touching exactly `arity` numbers.
+    pub fn symbols_with_arity(&self, arity: usize) -> Vec<&Symbol> {
+        self.symbols
+            .iter()
+            .filter(|symbol| self.numbers_around(symbol).len() == arity)
+            .collect()
+    }
+    /// Sum over the `kind` symbols touching exactly `arity` numbers of the product of those numbers,
+    /// `None` when it does not fit in u64.
+    ///
+    /// A symbol touching no number has no ratio, so an `arity` of 0 sums to 0.
+    pub fn gear_ratio(&self, kind: char, arity: usize) -> Option<u64> {
+        if arity == 0 {
+            return Some(0);
+        }
+        self.symbols_with_arity(arity)
+            .into_iter()
+            .filter(|symbol| symbol.kind == kind)
+            .try_fold(0u64, |sum, symbol| {
+                let ratio = self
+                    .numbers_around(symbol)
+                    .iter()
+                    .try_fold(1u64, |product, number| {
+                        product.checked_mul(number.value as u64)
+                    })?;
+                sum.checked_add(ratio)
+            })
+    }
+    fn numbers_touching<'a, I>(&self, symbols: I) -> Vec<&PartNumber>
+    where
+        I: IntoIterator<Item = &'a Symbol>,
+    {
+        symbols
+            .into_iter()
+            .flat_map(|symbol| {
+                self.grid
+                    .neighbours8(symbol.position)
+                    .filter_map(|position| self.number_at.get(&position).copied())
+            })
+            .collect::<BTreeSet<usize>>()
+            .into_iter()
+            .map(|idx| &self.numbers[idx])
+            .collect()
+    }
+}
+
+pub struct Day3;
+impl Solution for Day3 {
+    fn part1(&self, input: &str) -> Result<String, ParseError> {
+        let schematic = input.parse::<Schematic>()?;
+        Ok(schematic
+            .part_numbers()
+            .iter()
+            .map(|number| number.value as u64)
+            .sum::<u64>()
+            .to_string())
+    }
+    fn part2(&self, input: &str) -> Result<String, ParseError> {
+        let schematic = input.parse::<Schematic>()?;
+        let ratio = schematic.gear_ratio('*', 2);
+        ratio
+            .map(|ratio| ratio.to_string())
+            .ok_or_else(|| ParseError::at(input, input, "gear ratios summing within u64"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Day3, PartNumber, Schematic};
+    use common::Solution;
+
+    const EXAMPLE: &str = r"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    fn values(numbers: Vec<&PartNumber>) -> Vec<u32> {
+        numbers.iter().map(|number| number.value).collect()
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Day3.part1(EXAMPLE).unwrap(), "4361");
+        assert_eq!(Day3.part2(EXAMPLE).unwrap(), "467835");
+    }
+    #[test]
+    fn extract_numbers_and_symbols() {
+        let schematic = EXAMPLE.parse::<Schematic>().unwrap();
+        assert_eq!(schematic.numbers().len(), 10);
+        assert_eq!(
+            schematic.numbers()[1],
+            PartNumber {
+                value: 114,
+                row: 0,
+                start: 5,
+                end: 8
+            }
+        );
+        let kinds: String = schematic
+            .symbols()
+            .iter()
+            .map(|symbol| symbol.kind)
+            .collect();
+        assert_eq!(kinds, "*#*+$*");
+        assert_eq!(schematic.symbols()[1].position, (3, 6));
+    }
+    #[test]
+    fn query_adjacency() {
+        let schematic = EXAMPLE.parse::<Schematic>().unwrap();
+        assert_eq!(values(schematic.numbers_adjacent_to('#')), vec![633]);
+        assert_eq!(values(schematic.numbers_adjacent_to('$')), vec![664]);
+        assert_eq!(
+            values(schematic.numbers_adjacent_to('*')),
+            vec![467, 35, 617, 755, 598]
+        );
+        let lonely = schematic.symbols_with_arity(1);
+        let kinds: String = lonely.iter().map(|symbol| symbol.kind).collect();
+        assert_eq!(kinds, "#*+$");
+        assert_eq!(schematic.gear_ratio('*', 1), Some(617));
+        assert_eq!(schematic.gear_ratio('+', 1), Some(592));
+        assert_eq!(schematic.gear_ratio('*', 3), Some(0));
+        let isolated = "1.....\n....*.".parse::<Schematic>().unwrap();
+        assert_eq!(isolated.gear_ratio('*', 0), Some(0));
+    }
+    #[test]
+    fn report_overflowing_gear_ratios() {
+        let gear = "4294967295*4294967295";
+        let input = format!("{}\n{}\n{}", gear, ".".repeat(gear.len()), gear);
+        let schematic = input.parse::<Schematic>().unwrap();
+        assert_eq!(schematic.gear_ratio('*', 2), None);
+        let err = Day3.part2(&input).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.expected, "gear ratios summing within u64");
+        let ratio = 4294967295u64 * 4294967295;
+        assert_eq!(Day3.part2(gear).unwrap(), ratio.to_string());
+    }
+    #[test]
+    fn number_touching_two_symbols_counts_once() {
+        let schematic = "*12*".parse::<Schematic>().unwrap();
+        assert_eq!(values(schematic.part_numbers()), vec![12]);
+        assert_eq!(schematic.symbols_with_arity(1).len(), 2);
+    }
+    #[test]
+    fn report_oversized_number() {
+        let err = "..99999999999.".parse::<Schematic>().err().unwrap();
+        assert_eq!((err.line, err.column), (1, 3));
+    }
+}
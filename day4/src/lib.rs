@@ -0,0 +1,268 @@
+use std::{
+    collections::{BTreeSet, VecDeque},
+    fmt,
+    io::BufRead,
+    str::FromStr,
+};
+
+use common::{
+    input::{parse_lines, read_lines},
+    InputError, ParseError, Solution,
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete,
+    combinator::all_consuming,
+    multi::separated_list1,
+    sequence::preceded,
+    IResult,
+};
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Card {
+    pub id: u32,
+    pub winningnumbers: Vec<u32>,
+    pub scratched: Vec<u32>,
+    pub won_numbers: Vec<u32>
+}
+fn card_id(input: &str) -> IResult<&str, u32> {
+    let (input, _) = tag("Card")(input)?;
+    let (input, number) = preceded(complete::multispace1,complete::u32)(input)?;
+    Ok((input, number))
+}
+fn numbers(input: &str) -> IResult<&str, Vec<u32>> {
+    let (input, digits) = preceded(complete::multispace0,
+        separated_list1(complete::multispace1, complete::u32))(input)?;
+    Ok((input, digits))
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (input, id) = card_id(s).map_err(|err| ParseError::nom(s, err, "Card <id>"))?;
+        let (input, winningnumbers) =
+            preceded(tag(":"), preceded(complete::multispace0, numbers))(input)
+                .map_err(|err| ParseError::nom(s, err, "':' and winning numbers"))?;
+        let (input, scratched) =
+            preceded(preceded(complete::multispace0, tag("|")), numbers)(input)
+                .map_err(|err| ParseError::nom(s, err, "'|' and scratched numbers"))?;
+        all_consuming(complete::space0)(input)
+            .map_err(|err| ParseError::nom(s, err, "a number or end of line"))?;
+        let mut winningset: BTreeSet<u32> = BTreeSet::new();
+        winningset.extend(winningnumbers.iter());
+        let mut scratchedset: BTreeSet<u32> = BTreeSet::new();
+        scratchedset.extend(scratched.iter());
+        let won_numbers: Vec<u32> = winningset.intersection(&scratchedset).copied().collect();
+        Ok(Card {
+            id,
+            winningnumbers,
+            scratched,
+            won_numbers
+        })
+    }
+}
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |numbers: &[u32]| {
+            numbers
+                .iter()
+                .map(|number| number.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        write!(
+            f,
+            "Card {}: {} | {}",
+            self.id,
+            join(&self.winningnumbers),
+            join(&self.scratched)
+        )
+    }
+}
+fn calc_part1<E>(mut cards: impl Iterator<Item = Result<Card, E>>) -> Result<u64, E> {
+    cards.try_fold(0, |acc, card| {
+        let power = card?.won_numbers.iter().fold(0, |init, _| {
+            if init == 0 {
+                1
+            } else {
+                init * 2
+            }
+        });
+        Ok(acc + power)
+    })
+}
+/// Copies won by the cards seen so far for the cards still to come, nearest first.
+#[derive(Default)]
+struct PendingCopies {
+    copies: VecDeque<u64>,
+}
+impl PendingCopies {
+    /// Instances of the next card, handing one copy per instance to each of the `wins` cards after it.
+    fn next(&mut self, wins: usize) -> u64 {
+        let instances = 1 + self.copies.pop_front().unwrap_or(0);
+        if self.copies.len() < wins {
+            self.copies.resize(wins, 0);
+        }
+        for copies in self.copies.iter_mut().take(wins) {
+            *copies += instances;
+        }
+        instances
+    }
+}
+/// Number of instances of every card once all won copies are handed out, originals included.
+///
+/// Copies only ever flow to later cards, so a single pass keeping the copies
+/// owed to the next few cards is enough.
+pub fn copy_counts(cards: &[Card]) -> Vec<u64> {
+    let mut pending = PendingCopies::default();
+    cards
+        .iter()
+        .map(|card| pending.next(card.won_numbers.len()))
+        .collect()
+}
+fn calc_part2<E>(mut cards: impl Iterator<Item = Result<Card, E>>) -> Result<u64, E> {
+    let mut pending = PendingCopies::default();
+    cards.try_fold(0, |acc, card| Ok(acc + pending.next(card?.won_numbers.len())))
+}
+/// Every malformed card of `input`, then every card whose number of winning or
+/// scratched numbers differs from the first card.
+pub fn validate_cards(input: &str) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    let mut counts = None;
+    for (line, number) in input.lines().zip(1..) {
+        let card = match line.parse::<Card>() {
+            Ok(card) => card,
+            Err(err) => {
+                problems.push(err.in_line(number));
+                continue;
+            }
+        };
+        let (winning, scratched) =
+            *counts.get_or_insert((card.winningnumbers.len(), card.scratched.len()));
+        //both separators are there, the card parsed
+        let after = |separator: char| &line[line.find(separator).unwrap() + 1..];
+        if card.winningnumbers.len() != winning {
+            let expected = format!("{} winning numbers like the first card", winning);
+            problems.push(ParseError::at(line, after(':'), expected).in_line(number));
+        }
+        if card.scratched.len() != scratched {
+            let expected = format!("{} scratched numbers like the first card", scratched);
+            problems.push(ParseError::at(line, after('|'), expected).in_line(number));
+        }
+    }
+    problems
+}
+pub struct Day4;
+impl Solution for Day4 {
+    fn part1(&self, input: &str) -> Result<String, ParseError> {
+        Ok(calc_part1(parse_lines(input, Card::from_str))?.to_string())
+    }
+    fn part2(&self, input: &str) -> Result<String, ParseError> {
+        Ok(calc_part2(parse_lines(input, Card::from_str))?.to_string())
+    }
+    fn part1_from_reader(&self, input: &mut dyn BufRead) -> Result<String, InputError> {
+        Ok(calc_part1(read_lines(input, Card::from_str))?.to_string())
+    }
+    fn part2_from_reader(&self, input: &mut dyn BufRead) -> Result<String, InputError> {
+        Ok(calc_part2(read_lines(input, Card::from_str))?.to_string())
+    }
+    fn validate(&self, input: &str) -> Option<Vec<ParseError>> {
+        Some(validate_cards(input))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::{Card, calc_part1, calc_part2, copy_counts, validate_cards};
+    use common::input::{parse_lines, read_lines};
+    use std::str::FromStr;
+
+    #[test]
+    fn parse_single_card() {
+        let input = r"Card   8: 76  7 55  3 95 17 24 23 69 47 |  8 41 67 46 29 18  2 82 86 59 88 22 98 25 95 15 57 26 63  3 36 85  7 24 20";
+        let card = input.parse::<Card>().expect("Card shoul be parseable");
+        assert_eq!(card.id, 8);
+        assert_eq!(card.winningnumbers.len(), 10);
+        assert_eq!(card.scratched.len(), 25);
+    }
+    #[test]
+    fn parse_single_card_2() {
+        let input = r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+        let card = input.parse::<Card>().expect("Card shoul be parseable");
+        assert_eq!(card.id, 1);
+        assert_eq!(card.winningnumbers.len(), 5);
+        assert_eq!(card.scratched.len(), 8);
+    }
+    #[test]
+    fn parse_multiple_cards() {
+        let input = r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        let cards: Vec<Card> = input
+            .lines()
+            .map(|line| line.parse::<Card>().expect("Card shoul be parseable"))
+            .collect();
+        assert_eq!(13, calc_part1(parse_lines(input, Card::from_str)).unwrap());
+        let won: Vec<usize> = cards.iter().map(|card| card.won_numbers.len()).collect();
+        assert_eq!(won, vec![4, 2, 2, 1, 0, 0]);
+        assert_eq!(cards[0].won_numbers, vec![17, 48, 83, 86]);
+    }
+    #[test]
+    fn calc_second() {
+        let input = r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        let cards: Vec<Card> = input
+            .lines()
+            .map(|line| line.parse::<Card>().expect("Card shoul be parseable"))
+            .collect();
+        let result = calc_part2(read_lines(input.as_bytes(), Card::from_str)).unwrap();
+        assert_eq!(result, 30);
+        assert_eq!(copy_counts(&cards), vec![1, 2, 4, 8, 14, 1]);
+
+    }
+    #[test]
+    fn report_malformed_card() {
+        let input = r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 ! 61 30 68 82 17 32 24 19";
+        let err = calc_part1(parse_lines(input, Card::from_str)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 24));
+        assert_eq!(err.expected, "'|' and scratched numbers");
+        assert!(err.snippet.starts_with("! 61"));
+        let err = "Card 1: 1 2 | 1 4 junk".parse::<Card>().unwrap_err();
+        assert_eq!(
+            (err.column, err.expected.as_str()),
+            (19, "a number or end of line")
+        );
+        assert!("Card 1: 1 2 | 1 4 ".parse::<Card>().is_ok());
+    }
+    #[test]
+    fn validate_every_card() {
+        let input = r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 | 61 30 68 82 17 32 24 19
+Card x: 1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36";
+        let problems: Vec<(usize, usize, String)> = validate_cards(input)
+            .into_iter()
+            .map(|err| (err.line, err.column, err.expected))
+            .collect();
+        assert_eq!(
+            problems,
+            vec![
+                (2, 8, "5 winning numbers like the first card".to_string()),
+                (3, 6, "Card <id>".to_string()),
+                (4, 25, "8 scratched numbers like the first card".to_string()),
+            ]
+        );
+        assert!(validate_cards(input.lines().last().unwrap()).is_empty());
+    }
+
+}
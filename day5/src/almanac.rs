@@ -0,0 +1,876 @@
+use std::{fmt, iter, ops::Range, str::FromStr};
+
+use common::ParseError;
+use nom::{
+    bytes::complete::{is_not, tag, take_while},
+    character::complete::{self, multispace0, multispace1},
+    combinator::{all_consuming, opt, verify},
+    multi::separated_list1,
+    sequence::{pair, preceded, terminated},
+    IResult,
+};
+
+fn parse_seed_list(input: &str) -> IResult<&str, Vec<u64>> {
+    let (input, seeds) = preceded(
+        terminated(tag("seeds:"), complete::space0),
+        separated_list1(complete::space1, complete::u64),
+    )(input)?;
+    Ok((input, seeds))
+}
+/// Seeds come as `start length` pairs of non-empty ranges ending within u64, `line` being the
+/// parsed seeds line.
+fn check_seed_pairs(line: &str, seeds: &[u64]) -> Result<(), ParseError> {
+    if seeds.len() % 2 == 1 {
+        let expected = "a range length after the last seed";
+        return Err(ParseError::at(line, &line[line.len()..], expected));
+    }
+    let numbers: Vec<&str> = line["seeds:".len()..].split_whitespace().collect();
+    for (pair, numbers) in seeds.chunks_exact(2).zip(numbers.chunks_exact(2)) {
+        if pair[1] == 0 {
+            return Err(ParseError::at(line, numbers[1], "a non-zero range length"));
+        }
+        if pair[0].checked_add(pair[1]).is_none() {
+            let expected = "a seed range ending within u64";
+            return Err(ParseError::at(line, numbers[1], expected));
+        }
+    }
+    Ok(())
+}
+const RANGES_WITHIN_U64: &str = "a mapping whose ranges end within u64";
+/// A `<destination> <source> <length>` line, failing outright when either range ends past `u64::MAX`.
+fn parse_maping_entry(input: &str) -> IResult<&str, Vec<u64>> {
+    let (rest, numbers) = verify(
+        separated_list1(complete::space1, complete::u64),
+        |numbers: &[u64]| numbers.len() == 3,
+    )(input)?;
+    let fits = |start: u64| start.checked_add(numbers[2]).is_some();
+    if !fits(numbers[0]) || !fits(numbers[1]) {
+        return Err(nom::Err::Failure(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::Verify,
+        )));
+    }
+    Ok((rest, numbers))
+}
+fn parse_maping_title(input: &str) -> IResult<&str, &str> {
+    let (input, name) = terminated(is_not(" "), complete::multispace0)(input)?;
+    let (input, _) = take_while(|a: char| a != '\n')(input)?;
+    let (input, _) = opt(multispace0)(input)?;
+    Ok((input, name))
+}
+fn parse_maping(input: &str) -> IResult<&str, Map> {
+    let (input, name) = parse_maping_title(input)?;
+    let (input, entries) = separated_list1(complete::line_ending, parse_maping_entry)(input)?;
+    let elems = entries
+        .iter()
+        .map(|elem| Mapping::new(elem[0], elem[1], elem[2]))
+        .collect();
+    Ok((input, Map::new(name, elems)))
+}
+/// Every map block up to the end of the input, only whitespace may follow the last one.
+fn mappings(input: &str) -> IResult<&str, Vec<Map>> {
+    let (input, blocks) = all_consuming(terminated(
+        separated_list1(
+            pair(complete::line_ending, complete::line_ending),
+            parse_maping,
+        ),
+        multispace0,
+    ))(input)?;
+    Ok((input, blocks))
+}
+/// A map block without any mapping below its title.
+fn empty_block(block: Option<(&str, usize, usize)>) -> Option<ParseError> {
+    match block {
+        Some((title, number, 0)) => Some(
+            ParseError::at(title, &title[title.len()..], "mappings below the title")
+                .in_line(number),
+        ),
+        _ => None,
+    }
+}
+/// The maps of an almanac, in the order they lead from seeds to locations.
+pub const MAP_NAMES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// A run of consecutive values, `start` included and `end` excluded.
+pub type ValueRange = Range<u64>;
+
+/// The seeds and the chain of maps leading from seeds to locations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<Map>,
+}
+
+impl Almanac {
+    pub fn parse(source: &str) -> Result<Self, ParseError> {
+        let (input, seeds) = parse_seed_list(source)
+            .map_err(|err| ParseError::nom(source, err, "seeds: <numbers>"))?;
+        check_seed_pairs(&source[..source.len() - input.len()], &seeds)?;
+        let (input, _) = multispace1(input)
+            .map_err(|err| ParseError::nom(source, err, "a blank line after the seeds"))?;
+        let (_, maps) = mappings(input).map_err(|err| match err {
+            nom::Err::Failure(_) => ParseError::nom(source, err, RANGES_WITHIN_U64),
+            _ => ParseError::nom(source, err, "a map block"),
+        })?;
+        Ok(Almanac { seeds, maps })
+    }
+    /// Every problem of `source`, checked line by line: the seeds, then the maps of
+    /// [`MAP_NAMES`] in order, each a `<name> map:` title over lines of three numbers.
+    pub fn validate(source: &str) -> Vec<ParseError> {
+        let mut problems = Vec::new();
+        let seeds = source.lines().next().unwrap_or("").trim_end();
+        match all_consuming(parse_seed_list)(seeds) {
+            Ok((_, parsed)) => problems.extend(check_seed_pairs(seeds, &parsed).err()),
+            Err(err) => problems.push(ParseError::nom(seeds, err, "seeds: <numbers>")),
+        }
+        let mut next = 0;
+        //title, line number and mappings of the block being read
+        let mut block = None;
+        for (line, number) in source.lines().zip(1..).skip(1) {
+            let line = line.trim_end();
+            if line.is_empty() {
+                problems.extend(empty_block(block.take()));
+                continue;
+            }
+            if let Some((_, _, entries)) = &mut block {
+                if let Err(err) = all_consuming(parse_maping_entry)(line) {
+                    let expected = match err {
+                        nom::Err::Failure(_) => RANGES_WITHIN_U64,
+                        _ => "<destination> <source> <length>",
+                    };
+                    problems.push(ParseError::nom(line, err, expected).in_line(number));
+                }
+                *entries += 1;
+                continue;
+            }
+            block = Some((line, number, 0));
+            let name = line.strip_suffix(" map:");
+            match name.and_then(|name| MAP_NAMES.iter().position(|expected| *expected == name)) {
+                Some(position) if position >= next => {
+                    if position > next {
+                        let expected =
+                            format!("the {} map first", MAP_NAMES[next..position].join(", "));
+                        problems.push(ParseError::at(line, line, expected).in_line(number));
+                    }
+                    next = position + 1;
+                }
+                _ => {
+                    let expected = MAP_NAMES
+                        .get(next)
+                        .map_or("the end of the almanac".to_string(), |name| {
+                            format!("'{} map:'", name)
+                        });
+                    problems.push(ParseError::at(line, line, expected).in_line(number));
+                    //a misspelt title still stands for the expected map
+                    if line.ends_with("map:") {
+                        next += 1;
+                    }
+                }
+            }
+        }
+        problems.extend(empty_block(block));
+        if next < MAP_NAMES.len() {
+            let expected = format!("the {} map", MAP_NAMES[next..].join(", "));
+            problems.push(ParseError::at(source, &source[source.len()..], expected));
+        }
+        problems
+    }
+    /// The seed numbers, read one by one.
+    pub fn seeds(&self) -> &[u64] {
+        &self.seeds
+    }
+    /// The seed numbers read as `start len` pairs, all non-empty once parsed.
+    pub fn seed_ranges(&self) -> Vec<ValueRange> {
+        self.seeds
+            .chunks_exact(2)
+            .map(|pair| pair[0]..pair[0] + pair[1])
+            .collect()
+    }
+    pub fn maps(&self) -> &[Map] {
+        &self.maps
+    }
+    pub fn has_seed(&self, seed: u64) -> bool {
+        self.seed_ranges()
+            .iter()
+            .any(|range| range.contains(&seed))
+    }
+    pub fn seed_to_location(&self, seed: u64) -> u64 {
+        self.maps
+            .iter()
+            .fold(seed, |seed, maping| maping.source_to_destination(seed))
+    }
+    /// The seed leading to `location`, if it is one of the seed ranges.
+    pub fn location_to_seed(&self, location: u64) -> Option<u64> {
+        let seed = match self.trace_back(location).last() {
+            Some(step) => step.to?,
+            None => location,
+        };
+        if self.has_seed(seed) {
+            return Some(seed);
+        }
+        None
+    }
+    /// The locations of every seed range, as ranges.
+    pub fn location_ranges(&self) -> Vec<ValueRange> {
+        self.maps
+            .iter()
+            .fold(self.seed_ranges(), |ranges, mapping| {
+                mapping.ranges_to_destination(ranges)
+            })
+    }
+    /// All the maps composed into a single seed to location map.
+    pub fn composed(&self) -> Map {
+        self.maps
+            .iter()
+            .fold(Map::new("seed-to-seed", Vec::new()), |composed, map| composed.then(map))
+    }
+    /// The path of `seed` through every map, from seed to location.
+    pub fn trace(&self, seed: u64) -> Vec<Step<'_>> {
+        let mut value = seed;
+        self.maps
+            .iter()
+            .map(|map| {
+                let mapping = map.forward_mapping(value);
+                let step = Step {
+                    map: &map.name,
+                    mapping,
+                    from: value,
+                    to: Some(mapping.map_or(value, |mapping| mapping.from(value).unwrap())),
+                };
+                value = step.to.unwrap();
+                step
+            })
+            .collect()
+    }
+    /// The path of `location` back through every map, from location to seed.
+    /// Stops at the first map where no source produces the value.
+    pub fn trace_back(&self, location: u64) -> Vec<Step<'_>> {
+        let mut steps = Vec::new();
+        let mut value = location;
+        for map in self.maps.iter().rev() {
+            let step = match map.inverse_mapping(value) {
+                Some(mapping) => Step {
+                    map: &map.name,
+                    mapping: Some(mapping),
+                    from: value,
+                    to: mapping.to(value),
+                },
+                //a value inside a source range is sent elsewhere, so it cannot pass through
+                None => Step {
+                    map: &map.name,
+                    mapping: map.forward_mapping(value),
+                    from: value,
+                    to: map.forward_mapping(value).is_none().then_some(value),
+                },
+            };
+            steps.push(step);
+            match step.to {
+                Some(to) => value = to,
+                None => break,
+            }
+        }
+        steps
+    }
+}
+impl FromStr for Almanac {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Almanac::parse(s)
+    }
+}
+/// The seeds line, then every map block after a blank line.
+impl fmt::Display for Almanac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "seeds:")?;
+        for seed in &self.seeds {
+            write!(f, " {}", seed)?;
+        }
+        for map in &self.maps {
+            write!(f, "\n\n{}", map)?;
+        }
+        Ok(())
+    }
+}
+/// One map applied to a value, `mapping` is `None` when the value passes through unchanged.
+/// Tracing back, `to` is `None` when no source produces the value, `mapping` is then the one
+/// sending it elsewhere.
+#[derive(Debug, Clone, Copy)]
+pub struct Step<'a> {
+    pub map: &'a str,
+    pub mapping: Option<&'a Mapping>,
+    pub from: u64,
+    pub to: Option<u64>,
+}
+impl fmt::Display for Step<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.to, self.mapping) {
+            (Some(to), Some(mapping)) => {
+                write!(f, "{}: {} -> {} via {}", self.map, self.from, to, mapping)
+            }
+            (Some(to), None) => write!(f, "{}: {} -> {} (pass-through)", self.map, self.from, to),
+            (None, Some(mapping)) => {
+                write!(
+                    f,
+                    "{}: {} has no preimage, {} sends it away",
+                    self.map, self.from, mapping
+                )
+            }
+            (None, None) => write!(f, "{}: {} has no preimage", self.map, self.from),
+        }
+    }
+}
+/// Splits an `a-to-b` map name into `a` and `b`.
+fn endpoints(name: &str) -> Option<(&str, &str)> {
+    name.split_once("-to-")
+}
+/// Translates values through its mappings, values outside of all of them are kept as is.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct Map {
+    name: String,
+    elems: Vec<Mapping>,
+}
+impl Map {
+    pub fn new(name: impl Into<String>, elems: Vec<Mapping>) -> Self {
+        Map {
+            name: name.into(),
+            elems,
+        }
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn mappings(&self) -> &[Mapping] {
+        &self.elems
+    }
+    /// The mapping translating `source`, if any.
+    pub fn forward_mapping(&self, source: u64) -> Option<&Mapping> {
+        self.elems.iter().find(|maping| maping.from(source).is_some())
+    }
+    /// The mapping producing `destination`, if any.
+    pub fn inverse_mapping(&self, destination: u64) -> Option<&Mapping> {
+        self.elems.iter().find(|maping| maping.to(destination).is_some())
+    }
+    pub fn source_to_destination(&self, source: u64) -> u64 {
+        self.elems
+            .iter()
+            .find_map(|maping| maping.from(source))
+            .unwrap_or(source)
+    }
+    pub fn destination_to_source(&self, destination: u64) -> u64 {
+        self.elems
+            .iter()
+            .find_map(|maping| maping.to(destination))
+            .unwrap_or(destination)
+    }
+    /// Maps whole ranges, splitting them wherever they cross a mapping boundary.
+    pub fn ranges_to_destination(
+        &self,
+        ranges: impl IntoIterator<Item = ValueRange>,
+    ) -> Vec<ValueRange> {
+        self.pieces(ranges)
+            .into_iter()
+            .map(|(source, destination)| destination..destination + (source.end - source.start))
+            .collect()
+    }
+    /// The map equivalent to applying `self` then `next`.
+    ///
+    /// Values both maps keep as is are left out of the mappings, so they pass through too.
+    pub fn then(&self, next: &Map) -> Map {
+        let mut elems: Vec<Mapping> = self
+            .pieces(iter::once(0..u64::MAX))
+            .into_iter()
+            .flat_map(|(source, middle)| {
+                next.pieces(iter::once(middle..middle + (source.end - source.start)))
+                    .into_iter()
+                    .map(move |(through, to)| Mapping {
+                        from: source.start + (through.start - middle),
+                        to,
+                        number: through.end - through.start,
+                    })
+            })
+            .filter(|maping| maping.from != maping.to)
+            .collect();
+        elems.sort_by_key(|maping| maping.from);
+        let name = match (endpoints(&self.name), endpoints(&next.name)) {
+            (Some((from, _)), Some((_, to))) => format!("{}-to-{}", from, to),
+            _ => format!("{} then {}", self.name, next.name),
+        };
+        Map { name, elems }
+    }
+    /// The map undoing this one, assuming no two mappings share a destination.
+    pub fn inverse(&self) -> Map {
+        let name = match endpoints(&self.name) {
+            Some((from, to)) => format!("{}-to-{}", to, from),
+            None => format!("inverse of {}", self.name),
+        };
+        let elems = self
+            .elems
+            .iter()
+            .map(|maping| Mapping {
+                from: maping.to,
+                to: maping.from,
+                number: maping.number,
+            })
+            .collect();
+        Map { name, elems }
+    }
+    /// Cuts `ranges` along the mappings into source ranges and the start of their destination,
+    /// the first mapping covering a value wins.
+    fn pieces(&self, ranges: impl IntoIterator<Item = ValueRange>) -> Vec<(ValueRange, u64)> {
+        let mut pieces = Vec::new();
+        let ranges: Vec<ValueRange> = ranges.into_iter().collect();
+        let unmapped = self.elems.iter().fold(ranges, |unmapped, maping| {
+            unmapped
+                .into_iter()
+                .flat_map(|range| {
+                    let (inside, outside) = maping.split(range);
+                    pieces.extend(inside.map(|destination| {
+                        let start = maping.from + (destination.start - maping.to);
+                        (start..start + (destination.end - destination.start), destination.start)
+                    }));
+                    outside
+                })
+                .collect()
+        });
+        pieces.extend(unmapped.into_iter().map(|range| {
+            let start = range.start;
+            (range, start)
+        }));
+        pieces
+    }
+}
+/// Sends the `number` values starting at `from` to the ones starting at `to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mapping {
+    from: u64,
+    to: u64,
+    number: u64,
+}
+impl Mapping {
+    /// A mapping in almanac order: destination start, source start, length.
+    ///
+    /// Both ranges must end within `u64`, as the almanac parser checks.
+    pub fn new(to: u64, from: u64, number: u64) -> Self {
+        Mapping { from, to, number }
+    }
+    /// The values this mapping translates.
+    pub fn source(&self) -> ValueRange {
+        self.from..self.from.saturating_add(self.number)
+    }
+    /// The values this mapping produces.
+    pub fn destination(&self) -> ValueRange {
+        self.to..self.to.saturating_add(self.number)
+    }
+    pub fn from(&self, f: u64) -> Option<u64> {
+        self.source()
+            .contains(&f)
+            .then(|| self.to + (f - self.from))
+    }
+    pub fn to(&self, t: u64) -> Option<u64> {
+        self.destination()
+            .contains(&t)
+            .then(|| self.from + (t - self.to))
+    }
+    /// Splits `range` into its translated overlap with this mapping and the parts left
+    /// outside of it.
+    pub fn split(&self, range: ValueRange) -> (Option<ValueRange>, Vec<ValueRange>) {
+        let source = self.source();
+        let overlap = range.start.max(source.start)..range.end.min(source.end);
+        if overlap.is_empty() {
+            return (None, vec![range]);
+        }
+        let mut outside = Vec::new();
+        if range.start < overlap.start {
+            outside.push(range.start..overlap.start);
+        }
+        if overlap.end < range.end {
+            outside.push(overlap.end..range.end);
+        }
+        let start = self.to + (overlap.start - self.from);
+        (Some(start..start + (overlap.end - overlap.start)), outside)
+    }
+}
+/// The `<name> map:` title, then a line per mapping.
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} map:", self.name)?;
+        for mapping in &self.elems {
+            write!(f, "\n{}", mapping)?;
+        }
+        Ok(())
+    }
+}
+/// The almanac line of the mapping, `<destination> <source> <length>`.
+impl fmt::Display for Mapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.to, self.from, self.number)
+    }
+}
+#[cfg(test)]
+pub(crate) mod test {
+    use std::iter;
+
+    use proptest::prelude::*;
+
+    use super::{
+        mappings, parse_maping, parse_maping_entry, parse_maping_title, parse_seed_list, Almanac,
+        Map, Mapping, ValueRange,
+    };
+
+    pub(crate) const EXAMPLE: &str = r"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    fn block(index: usize) -> Map {
+        Almanac::parse(EXAMPLE).unwrap().maps[index].clone()
+    }
+    /// Every value covered by `ranges`, sorted.
+    fn values(ranges: Vec<ValueRange>) -> Vec<u64> {
+        let mut values: Vec<u64> = ranges
+            .into_iter()
+            .flatten()
+            .collect();
+        values.sort();
+        values
+    }
+
+    /// Maps whose sources are disjoint and whose destinations are disjoint too, so they can be
+    /// inverted: `(len, gap)` blocks laid out in order for the sources and shuffled for the
+    /// destinations.
+    fn invertible_map() -> impl Strategy<Value = Map> {
+        prop::collection::vec((1u64..40, 0u64..10), 1..6)
+            .prop_flat_map(|blocks| {
+                let order: Vec<usize> = (0..blocks.len()).collect();
+                (Just(blocks), Just(order).prop_shuffle(), 0u64..100, 0u64..100)
+            })
+            .prop_map(|(blocks, order, source_base, destination_base)| {
+                let mut sources = Vec::new();
+                let mut start = source_base;
+                for (len, gap) in &blocks {
+                    sources.push(start);
+                    start += len + gap;
+                }
+                let mut destination = destination_base;
+                let mut elems = Vec::new();
+                for idx in order {
+                    let (len, gap) = blocks[idx];
+                    elems.push(Mapping::new(destination, sources[idx], len));
+                    destination += len + gap;
+                }
+                Map::new("a-to-b", elems)
+            })
+    }
+
+    #[test]
+    fn range_ends_are_excluded() {
+        let block = block(0);
+        //98 and 99 go through "50 98 2", 100 is past its end
+        assert_eq!(block.source_to_destination(99), 51);
+        assert_eq!(block.source_to_destination(100), 100);
+        assert_eq!(block.destination_to_source(52), 50);
+        assert_eq!(block.destination_to_source(100), 100);
+        assert_eq!(block.mappings()[0].source(), 98..100);
+        assert_eq!(block.mappings()[0].destination(), 50..52);
+    }
+    proptest! {
+        #[test]
+        fn inverse_undoes_every_mapped_value(map in invertible_map()) {
+            let inverse = map.inverse();
+            for mapping in map.mappings() {
+                for value in mapping.source() {
+                    let destination = map.source_to_destination(value);
+                    prop_assert!(mapping.destination().contains(&destination));
+                    prop_assert_eq!(map.destination_to_source(destination), value);
+                    prop_assert_eq!(inverse.source_to_destination(destination), value);
+                }
+                let mapped = map.ranges_to_destination(iter::once(mapping.source()));
+                prop_assert_eq!(mapped, vec![mapping.destination()]);
+            }
+        }
+        #[test]
+        fn mappings_ending_at_the_top(
+            number in 1u64..1000,
+            to_gap in 0u64..1000,
+            from_gap in 0u64..1000,
+        ) {
+            let (to, from) = (u64::MAX - number - to_gap, u64::MAX - number - from_gap);
+            let mapping = Mapping::new(to, from, number);
+            prop_assert_eq!(mapping.from(from + number - 1), Some(to + number - 1));
+            prop_assert_eq!(mapping.to(to + number - 1), Some(from + number - 1));
+            prop_assert_eq!(mapping.from(u64::MAX), None);
+            prop_assert_eq!(mapping.to(u64::MAX), None);
+            let (inside, _) = mapping.split(from..u64::MAX);
+            prop_assert_eq!(inside, Some(to..to + number));
+            let map = Map::new("a-to-b", vec![mapping]);
+            let ranges = map.ranges_to_destination(iter::once(from - 1..u64::MAX));
+            let covered: u64 = ranges.iter().map(|range| range.end - range.start).sum();
+            prop_assert_eq!(covered, u64::MAX - from + 1);
+        }
+        #[test]
+        fn edges_of_a_mapping(to in 1u64..1000, from in 1u64..1000, number in 1u64..1000) {
+            let mapping = Mapping::new(to, from, number);
+            prop_assert_eq!(mapping.from(from), Some(to));
+            prop_assert_eq!(mapping.from(from + number - 1), Some(to + number - 1));
+            prop_assert_eq!(mapping.from(from + number), None);
+            prop_assert_eq!(mapping.from(from - 1), None);
+            prop_assert_eq!(mapping.to(to), Some(from));
+            prop_assert_eq!(mapping.to(to + number - 1), Some(from + number - 1));
+            prop_assert_eq!(mapping.to(to + number), None);
+            prop_assert_eq!(mapping.to(to - 1), None);
+            let (inside, outside) = mapping.split(from - 1..from + number + 1);
+            prop_assert_eq!(inside, Some(to..to + number));
+            prop_assert_eq!(outside, vec![from - 1..from, from + number..from + number + 1]);
+        }
+    }
+    #[test]
+    fn parse_seeds() {
+        let input = "seeds: 79 14 55 13";
+        let (_, seeds) = parse_seed_list(input).unwrap();
+        assert_eq!(seeds, vec![79, 14, 55, 13]);
+    }
+    #[test]
+    fn report_malformed_seeds() {
+        let err = Almanac::parse("seeds 79 14").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.expected, "seeds: <numbers>");
+    }
+    #[test]
+    fn validate_every_block() {
+        assert!(Almanac::validate(EXAMPLE).is_empty());
+        let input = EXAMPLE
+            .replace("seeds: 79 14", "seeds: 79 x14")
+            .replace("0 15 37", "0 15")
+            .replace("fertilizer-to-water map:", "fertiliser-to-water map:")
+            .replace("water-to-light map:\n88 18 7\n18 25 70\n\n", "")
+            .replace("0 69 1\n1 0 69", "");
+        let problems: Vec<(usize, usize, String)> = Almanac::validate(&input)
+            .into_iter()
+            .map(|err| (err.line, err.column, err.expected))
+            .collect();
+        assert_eq!(
+            problems,
+            vec![
+                (1, 10, "seeds: <numbers>".to_string()),
+                (8, 1, "<destination> <source> <length>".to_string()),
+                (12, 1, "'fertilizer-to-water map:'".to_string()),
+                (18, 1, "the water-to-light map first".to_string()),
+                (23, 29, "mappings below the title".to_string()),
+            ]
+        );
+        let input = EXAMPLE
+            .replace("seeds: 79 14 55 13", "seeds: 79")
+            .replace("50 98 2", "18446744073709551610 0 20");
+        let problems: Vec<(usize, String)> = Almanac::validate(&input)
+            .into_iter()
+            .map(|err| (err.line, err.expected))
+            .collect();
+        assert_eq!(
+            problems,
+            vec![
+                (1, "a range length after the last seed".to_string()),
+                (4, "a mapping whose ranges end within u64".to_string()),
+            ]
+        );
+        let problems = Almanac::validate(&EXAMPLE.replace("55 13", "55 0"));
+        assert_eq!((problems[0].line, problems[0].column), (1, 17));
+        let problems = Almanac::validate("seeds: 1 2\n\nseed-to-soil map:\n1 2 3\n");
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].line, 5);
+        assert!(problems[0].expected.starts_with("the soil-to-fertilizer, "));
+    }
+    #[test]
+    fn reject_overflowing_mappings() {
+        let top = "seeds: 1 10\n\nseed-to-soil map:\n18446744073709551605 0 10";
+        assert_eq!(
+            Almanac::parse(top).unwrap().seed_to_location(1),
+            u64::MAX - 9
+        );
+        let err = Almanac::parse(&top.replace(" 0 10", " 0 20")).unwrap_err();
+        assert_eq!((err.line, err.column), (4, 1));
+        assert_eq!(err.expected, "a mapping whose ranges end within u64");
+        let err = Almanac::parse("seeds: 1 10\n\nseed-to-soil map:\n0 18446744073709551610 20")
+            .unwrap_err();
+        assert_eq!(err.line, 4);
+    }
+    #[test]
+    fn reject_unpaired_seeds() {
+        let err = Almanac::parse(&EXAMPLE.replace("55 13", "55")).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 16));
+        assert_eq!(err.expected, "a range length after the last seed");
+        let err = Almanac::parse(&EXAMPLE.replace("79 14", "79 0")).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 11));
+        assert_eq!(err.expected, "a non-zero range length");
+        let err = Almanac::parse(&EXAMPLE.replace("79 14", "18446744073709551615 5")).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 29));
+        assert_eq!(err.expected, "a seed range ending within u64");
+        let almanac = Almanac::parse(&EXAMPLE.replace("79 14", "18446744073709551610 5"));
+        assert_eq!(almanac.unwrap().seed_ranges()[0].end, u64::MAX);
+        let problems = Almanac::validate(&EXAMPLE.replace("55 13", "18446744073709551615 5"));
+        assert_eq!(problems[0].expected, "a seed range ending within u64");
+    }
+    #[test]
+    fn reject_trailing_input() {
+        assert!(Almanac::parse(&format!("{}\n\n", EXAMPLE)).is_ok());
+        let err = Almanac::parse(&EXAMPLE.replace("56 93 4", "56 93 4\n\nnot a map")).unwrap_err();
+        assert_eq!(err.line, 35);
+        assert_eq!(err.snippet, "not a map");
+        let err = Almanac::parse(&EXAMPLE.replace("0 15 37", "0 15 37 x")).unwrap_err();
+        assert_eq!((err.line, err.column), (8, 9));
+    }
+    #[test]
+    fn parse_title() {
+        let input = r"seed-to-soil map:";
+        assert_eq!(parse_maping_title(input).unwrap().1, "seed-to-soil");
+    }
+    #[test]
+    fn parse_entry() {
+        let input = r"45 77 23";
+        assert_eq!(parse_maping_entry(input).unwrap().1, vec![45, 77, 23]);
+    }
+    #[test]
+    fn parse_block() {
+        let input = r"seed-to-soil map:
+50 98 2
+52 50 48";
+        let block = parse_maping(input).unwrap().1;
+        assert_eq!(block.name, "seed-to-soil");
+        assert_eq!(block.elems.len(), 2);
+        assert_eq!(block.source_to_destination(79), 81);
+        assert_eq!(block.destination_to_source(81), 79);
+        assert_eq!(block.source_to_destination(14), 14);
+        assert_eq!(block.destination_to_source(14), 14);
+        assert_eq!(block.source_to_destination(55), 57);
+        assert_eq!(block.destination_to_source(57), 55);
+        assert_eq!(block.source_to_destination(13), 13);
+        assert_eq!(block.destination_to_source(13), 13);
+        let mut ranges = block.ranges_to_destination(vec![79..93, 55..68]);
+        ranges.sort_by_key(|range| range.start);
+        assert_eq!(ranges, vec![57..70, 81..95]);
+    }
+    #[test]
+    fn split_range_at_boundaries() {
+        let input = r"seed-to-soil map:
+50 98 2
+52 50 48";
+        let block = parse_maping(input).unwrap().1;
+        let mut ranges = block.ranges_to_destination(iter::once(45..105));
+        ranges.sort_by_key(|range| range.start);
+        assert_eq!(ranges, vec![45..50, 50..52, 52..100, 100..105]);
+    }
+    #[test]
+    fn parse_multiple_block() {
+        let input = &EXAMPLE[EXAMPLE.find("seed-to-soil").unwrap()..];
+        let mappings = mappings(input).unwrap().1;
+        assert_eq!(mappings.len(), 7);
+    }
+    #[test]
+    fn parse_whole_data() {
+        let almanac = Almanac::parse(EXAMPLE).unwrap();
+        assert_eq!(almanac.seeds(), [79, 14, 55, 13]);
+        assert_eq!(almanac.seed_ranges(), vec![79..93, 55..68]);
+        assert_eq!(almanac.maps.len(), 7);
+        assert_eq!(almanac.seed_to_location(79), 82);
+        assert_eq!(almanac.seed_to_location(13), 35);
+        assert!(almanac.has_seed(79));
+        assert_eq!(almanac.location_to_seed(46), Some(82));
+    }
+    #[test]
+    fn trace_a_seed() {
+        let almanac = Almanac::parse(EXAMPLE).unwrap();
+        let steps: Vec<String> = almanac.trace(79).iter().map(|step| step.to_string()).collect();
+        assert_eq!(steps[0], "seed-to-soil: 79 -> 81 via 52 50 48");
+        assert_eq!(steps[1], "soil-to-fertilizer: 81 -> 81 (pass-through)");
+        assert_eq!(steps[6], "humidity-to-location: 78 -> 82 via 60 56 37");
+        let back = almanac.trace_back(82);
+        assert_eq!(back[0].map, "humidity-to-location");
+        let values: Vec<u64> = back.iter().filter_map(|step| step.to).collect();
+        assert_eq!(values, vec![78, 78, 74, 81, 81, 81, 79]);
+    }
+    #[test]
+    fn trace_back_without_preimage() {
+        let almanac = Almanac::parse("seeds: 98 2\n\nseed-to-soil map:\n50 98 2").unwrap();
+        let back = almanac.trace_back(98);
+        assert_eq!(back.len(), 1);
+        assert_eq!(back[0].to, None);
+        assert_eq!(
+            back[0].to_string(),
+            "seed-to-soil: 98 has no preimage, 50 98 2 sends it away"
+        );
+        assert_eq!(almanac.location_to_seed(98), None);
+        assert_eq!(almanac.location_to_seed(50), Some(98));
+        assert_eq!(
+            almanac.trace_back(40)[0].to_string(),
+            "seed-to-soil: 40 -> 40 (pass-through)"
+        );
+    }
+    #[test]
+    fn compose_two_maps() {
+        let (soil, fertilizer) = (block(0), block(1));
+        let composed = soil.then(&fertilizer);
+        assert_eq!(composed.name(), "seed-to-fertilizer");
+        let separately =
+            fertilizer.ranges_to_destination(soil.ranges_to_destination(iter::once(0..120)));
+        assert_eq!(values(composed.ranges_to_destination(iter::once(0..120))), values(separately));
+        assert_eq!(composed.source_to_destination(79), 81);
+        assert_eq!(composed.source_to_destination(14), 53);
+    }
+    #[test]
+    fn compose_whole_almanac() {
+        let almanac = Almanac::parse(EXAMPLE).unwrap();
+        let composed = almanac.composed();
+        assert_eq!(composed.name(), "seed-to-location");
+        assert_eq!(
+            values(composed.ranges_to_destination(almanac.seed_ranges())),
+            values(almanac.location_ranges())
+        );
+    }
+    #[test]
+    fn invert_a_map() {
+        let light = block(3);
+        let inverse = light.inverse();
+        assert_eq!(inverse.name(), "light-to-water");
+        assert_eq!(inverse.inverse(), light);
+        let round_trip =
+            inverse.ranges_to_destination(light.ranges_to_destination(iter::once(0..100)));
+        assert_eq!(values(round_trip), (0..100).collect::<Vec<u64>>());
+    }
+}
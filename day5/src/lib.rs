@@ -0,0 +1,40 @@
+use common::{ParseError, Solution};
+
+mod almanac;
+pub mod part1;
+pub mod part2;
+
+pub use almanac::{Almanac, Map, Mapping, Step, ValueRange, MAP_NAMES};
+
+pub struct Day5;
+impl Solution for Day5 {
+    fn part1(&self, input: &str) -> Result<String, ParseError> {
+        let location = part1::lowest_location(&Almanac::parse(input)?);
+        //parsing guarantees at least one seed, and only non-empty seed ranges
+        Ok(location.expect("a parsed almanac has seeds").to_string())
+    }
+    fn part2(&self, input: &str) -> Result<String, ParseError> {
+        let location = part2::lowest_location(&Almanac::parse(input)?);
+        //parsing guarantees at least one seed, and only non-empty seed ranges
+        Ok(location.expect("a parsed almanac has seeds").to_string())
+    }
+    /// Traces `value` as a seed to its location, then that location back to a seed.
+    fn explain(&self, input: &str, value: u64) -> Option<Result<String, ParseError>> {
+        Some(Almanac::parse(input).map(|almanac| {
+            let mut report = format!("seed {}:\n", value);
+            let steps = almanac.trace(value);
+            for step in &steps {
+                report += &format!("  {}\n", step);
+            }
+            let location = steps.last().and_then(|step| step.to).unwrap_or(value);
+            report += &format!("location {}:\n", location);
+            for step in almanac.trace_back(location) {
+                report += &format!("  {}\n", step);
+            }
+            report
+        }))
+    }
+    fn validate(&self, input: &str) -> Option<Vec<ParseError>> {
+        Some(Almanac::validate(input))
+    }
+}
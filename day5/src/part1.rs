@@ -0,0 +1,21 @@
+use crate::Almanac;
+
+/// Lowest location of the seeds read one by one.
+pub fn lowest_location(almanac: &Almanac) -> Option<u64> {
+    almanac
+        .seeds()
+        .iter()
+        .map(|seed| almanac.seed_to_location(*seed))
+        .min()
+}
+#[cfg(test)]
+mod test {
+    use super::lowest_location;
+    use crate::{almanac::test::EXAMPLE, Almanac};
+
+    #[test]
+    fn lowest_of_seeds() {
+        let almanac = Almanac::parse(EXAMPLE).unwrap();
+        assert_eq!(lowest_location(&almanac), Some(35));
+    }
+}
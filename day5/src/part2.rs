@@ -0,0 +1,22 @@
+use crate::Almanac;
+
+/// Lowest location of the seeds read as `start len` ranges.
+pub fn lowest_location(almanac: &Almanac) -> Option<u64> {
+    almanac
+        .location_ranges()
+        .iter()
+        .filter(|range| !range.is_empty())
+        .map(|range| range.start)
+        .min()
+}
+#[cfg(test)]
+mod test {
+    use super::lowest_location;
+    use crate::{almanac::test::EXAMPLE, Almanac};
+
+    #[test]
+    fn lowest_of_seed_ranges() {
+        let almanac = Almanac::parse(EXAMPLE).unwrap();
+        assert_eq!(lowest_location(&almanac), Some(46));
+    }
+}
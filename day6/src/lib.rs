@@ -0,0 +1,176 @@
+use common::{ParseError, Solution};
+use nom::{
+    character::complete::{multispace0, newline},
+    combinator::all_consuming,
+    sequence::separated_pair,
+    IResult,
+};
+
+pub mod part1;
+pub mod part2;
+
+/// Counts the hold times `h` in `0..=time` that beat the record, i.e. `h * (time - h) > record`.
+///
+/// The winning hold times lie strictly between the roots of `h^2 - time * h + record`,
+/// so the first one is found from the integer square root of the discriminant and
+/// nudged onto the exact boundary, the rest follows from the symmetry around `time / 2`.
+pub fn ways_to_win(time: u64, record: u64) -> u64 {
+    let (time, record) = (time as u128, record as u128);
+    let beats = |hold: u128| hold * (time - hold) > record;
+    let discriminant = match (time * time).checked_sub(4 * record) {
+        Some(discriminant) => discriminant,
+        None => return 0,
+    };
+    let mut first = (time - discriminant.isqrt()) / 2;
+    while first <= time / 2 && !beats(first) {
+        first += 1;
+    }
+    while first > 0 && beats(first - 1) {
+        first -= 1;
+    }
+    if first > time / 2 {
+        return 0;
+    }
+    (time - 2 * first + 1) as u64
+}
+
+/// Points at the first distance of `line`, a slice of `source`, without a time, or past the
+/// last one when some are missing.
+fn distance_count(source: &str, line: &str, times: usize, distances: usize) -> Option<ParseError> {
+    if times == distances {
+        return None;
+    }
+    let rest = line
+        .split_whitespace()
+        .nth(times + 1)
+        .unwrap_or(&line[line.len()..]);
+    let expected = format!("{} distances, one per time", times);
+    Some(ParseError::at(source, rest, expected))
+}
+/// Reads the `Time:` and `Distance:` lines of a sheet with the parsers of a part, failing on
+/// anything after them and on a distance count other than the time count.
+pub(crate) fn parse_sheet<'a, T>(
+    s: &'a str,
+    time: fn(&'a str) -> IResult<&'a str, Vec<T>>,
+    distance: fn(&'a str) -> IResult<&'a str, Vec<T>>,
+) -> Result<(Vec<T>, Vec<T>), ParseError> {
+    let (rest, (times, distances)) = separated_pair(time, newline, distance)(s)
+        .map_err(|err| ParseError::nom(s, err, "Time: and Distance: lines"))?;
+    all_consuming(multispace0)(rest)
+        .map_err(|err| ParseError::nom(s, err, "the end of the sheet"))?;
+    let line = s.lines().nth(1).unwrap_or_default();
+    match distance_count(s, line, times.len(), distances.len()) {
+        Some(err) => Err(err),
+        None => Ok((times, distances)),
+    }
+}
+
+/// Every problem of a record sheet: its `Time:` and `Distance:` lines, as many
+/// distances as times, and nothing after them.
+pub fn validate(input: &str) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    let mut lines = input.lines().zip(1..);
+    let mut times = None;
+    match lines.next() {
+        Some((line, number)) => match all_consuming(part1::parse_time)(line.trim_end()) {
+            Ok((_, parsed)) => times = Some(parsed.len()),
+            Err(err) => {
+                problems.push(ParseError::nom(line, err, "Time: <numbers>").in_line(number))
+            }
+        },
+        None => problems.push(ParseError::at(input, input, "a Time: line")),
+    }
+    match lines.next() {
+        Some((line, number)) => match all_consuming(part1::parse_distance)(line.trim_end()) {
+            Ok((_, distances)) => {
+                let count =
+                    times.and_then(|times| distance_count(line, line, times, distances.len()));
+                problems.extend(count.map(|err| err.in_line(number)));
+            }
+            Err(err) => {
+                problems.push(ParseError::nom(line, err, "Distance: <numbers>").in_line(number))
+            }
+        },
+        None => problems.push(ParseError::at(
+            input,
+            &input[input.len()..],
+            "a Distance: line",
+        )),
+    }
+    for (line, number) in lines.filter(|(line, _)| !line.trim().is_empty()) {
+        problems.push(ParseError::at(line, line, "the end of the sheet").in_line(number));
+    }
+    problems
+}
+
+pub struct Day6;
+impl Solution for Day6 {
+    fn part1(&self, input: &str) -> Result<String, ParseError> {
+        Ok(input
+            .parse::<part1::RecordTable>()?
+            .ways_product()
+            .to_string())
+    }
+    fn part2(&self, input: &str) -> Result<String, ParseError> {
+        Ok(input.parse::<part2::RecordTable>()?.ways().to_string())
+    }
+    fn validate(&self, input: &str) -> Option<Vec<ParseError>> {
+        Some(validate(input))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use crate::{validate, ways_to_win};
+
+    fn brute_force_ways(time: u64, record: u64) -> u64 {
+        (0..=time)
+            .map(|hold_time| hold_time * (time - hold_time))
+            .filter(|distance| *distance > record)
+            .count() as u64
+    }
+
+    #[test]
+    fn perfect_square_roots_do_not_win() {
+        assert_eq!(ways_to_win(10, 21), 3);
+        assert_eq!(ways_to_win(10, 24), 1);
+        assert_eq!(ways_to_win(10, 25), 0);
+        assert_eq!(ways_to_win(30, 200), 9);
+    }
+    #[test]
+    fn no_overflow_on_huge_races() {
+        assert_eq!(ways_to_win(u64::MAX, 0), u64::MAX - 1);
+        assert_eq!(ways_to_win(u64::MAX, u64::MAX), u64::MAX - 3);
+        assert_eq!(ways_to_win(0, u64::MAX), 0);
+    }
+    #[test]
+    fn validate_the_sheet() {
+        assert!(validate("Time:      7  15   30\nDistance:  9  40  200\n").is_empty());
+        let problems: Vec<(usize, usize, String)> =
+            validate("Time:      7  15   30\nDistance:  9  40  200  12\nTime: 3")
+                .into_iter()
+                .map(|err| (err.line, err.column, err.expected))
+                .collect();
+        assert_eq!(
+            problems,
+            vec![
+                (2, 24, "3 distances, one per time".to_string()),
+                (3, 1, "the end of the sheet".to_string()),
+            ]
+        );
+        let problems = validate("Time: 7 x\nDistance: 9");
+        assert_eq!((problems[0].line, problems[0].column), (1, 8));
+        assert_eq!(problems.len(), 1);
+        let problems = validate("Time: 7 15\nDistance: 9");
+        assert_eq!((problems[0].line, problems[0].column), (2, 12));
+        assert_eq!(validate("Time: 7")[0].expected, "a Distance: line");
+    }
+    proptest! {
+        #[test]
+        fn matches_brute_force(time in 0u64..300, record in 0u64..25_000) {
+            prop_assert_eq!(ways_to_win(time, record), brute_force_ways(time, record));
+        }
+    }
+}
@@ -1,48 +1,39 @@
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
 
+use common::ParseError;
 use nom::{
-    character::complete::{self, multispace0, multispace1, newline},
+    character::complete::{self, multispace0, multispace1},
     multi::separated_list1,
-    sequence::{preceded, separated_pair},
+    sequence::preceded,
     IResult,
 };
 
-fn main() {
-    let buffer = std::fs::read_to_string("data.txt").unwrap();
-    let records = RecordTable::from_str(&buffer).unwrap();
-    let ways = records
-        .timings
-        .iter()
-        .zip(records.distances.iter())
-        .fold(1, |acc, (time, distance)| {
-            acc * RecordTable::get_ways_to_win(*time, *distance)
-        });
-    println!("{}", ways);
-}
-
-struct RecordTable {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordTable {
     timings: Vec<u32>,
     distances: Vec<u32>,
 }
 impl RecordTable {
-    fn calc_distance(hold_time: u32, total_time: u32) -> u32 {
-        hold_time * (total_time - hold_time)
+    fn get_ways_to_win(time: u32, record: u32) -> u64 {
+        crate::ways_to_win(time as u64, record as u64)
     }
-    fn get_ways_to_win(time: u32, record: u32) -> usize {
-        (0..=time)
-            .map(|hold_time| RecordTable::calc_distance(hold_time, time))
-            .filter(|distance| *distance > record)
-            .count()
+    pub fn ways_product(&self) -> u64 {
+        self.timings
+            .iter()
+            .zip(self.distances.iter())
+            .fold(1, |acc, (time, distance)| {
+                acc * RecordTable::get_ways_to_win(*time, *distance)
+            })
     }
 }
-fn parse_time(input: &str) -> IResult<&str, Vec<u32>> {
+pub(crate) fn parse_time(input: &str) -> IResult<&str, Vec<u32>> {
     let (input, timings) = preceded(
         nom::bytes::complete::tag("Time:"),
         preceded(multispace0, separated_list1(multispace1, complete::u32)),
     )(input)?;
     Ok((input, timings))
 }
-fn parse_distance(input: &str) -> IResult<&str, Vec<u32>> {
+pub(crate) fn parse_distance(input: &str) -> IResult<&str, Vec<u32>> {
     let (input, timings) = preceded(
         nom::bytes::complete::tag("Distance:"),
         preceded(multispace0, separated_list1(multispace1, complete::u32)),
@@ -51,18 +42,35 @@ fn parse_distance(input: &str) -> IResult<&str, Vec<u32>> {
 }
 
 impl FromStr for RecordTable {
-    type Err = std::io::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_, (timings, distances)) =
-            separated_pair(parse_time, newline, parse_distance)(s).unwrap();
+        let (timings, distances) = crate::parse_sheet(s, parse_time, parse_distance)?;
         Ok(RecordTable { timings, distances })
     }
 }
 
+impl fmt::Display for RecordTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |values: &[u32]| {
+            values
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        write!(
+            f,
+            "Time: {}\nDistance: {}",
+            join(&self.timings),
+            join(&self.distances)
+        )
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::RecordTable;
+    use super::RecordTable;
 
     #[test]
     fn load_input() {
@@ -71,13 +79,7 @@ Distance:  9  40  200";
         let records = input.parse::<RecordTable>().unwrap();
         assert_eq!(records.timings.len(), 3);
         assert_eq!(records.distances.len(), 3);
-        let ways = records
-            .timings
-            .iter()
-            .zip(records.distances.iter())
-            .fold(1, |acc, (time, distance)| {
-                acc * RecordTable::get_ways_to_win(*time, *distance)
-            });
+        let ways = records.ways_product();
         assert_eq!(
             RecordTable::get_ways_to_win(records.timings[0], records.distances[0]),
             4
@@ -92,4 +94,26 @@ Distance:  9  40  200";
         );
         assert_eq!(ways, 288);
     }
+    #[test]
+    fn report_malformed_distance() {
+        let input = "Time:      7  15   30\nDistance  9  40  200";
+        let err = input.parse::<RecordTable>().err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+    #[test]
+    fn reject_leftovers_and_missing_distances() {
+        assert!("Time: 7 15\nDistance: 9 40\n\n"
+            .parse::<RecordTable>()
+            .is_ok());
+        let err = "Time: 7 15\nDistance: 9 40 x\nmore junk"
+            .parse::<RecordTable>()
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (2, 16));
+        assert_eq!(err.expected, "the end of the sheet");
+        let err = "Time: 7 15\nDistance: 9"
+            .parse::<RecordTable>()
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (2, 12));
+        assert_eq!(err.expected, "2 distances, one per time");
+    }
 }
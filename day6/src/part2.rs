@@ -1,67 +1,74 @@
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
 
+use common::ParseError;
 use nom::{
-    character::complete::{self, multispace0, multispace1, newline},
+    character::complete::{self, multispace0, multispace1},
     multi::separated_list1,
-    sequence::{preceded, separated_pair},
+    sequence::preceded,
     IResult,
 };
 
-fn main() {
-    let buffer = std::fs::read_to_string("data.txt").unwrap();
-    let records = RecordTable::from_str(&buffer).unwrap();
-    println!("{}", RecordTable::get_ways_to_win(records.timing, records.distance));
-}
-
-struct RecordTable {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordTable {
     timing: u64,
     distance: u64,
 }
 impl RecordTable {
-    fn calc_distance(hold_time: u64, total_time: u64) -> u64 {
-        hold_time * (total_time - hold_time)
+    pub fn ways(&self) -> u64 {
+        RecordTable::get_ways_to_win(self.timing, self.distance)
     }
-    fn get_ways_to_win(time: u64, record: u64) -> usize {
-        (0..=time)
-            .map(|hold_time| RecordTable::calc_distance(hold_time, time))
-            .filter(|distance| *distance > record)
-            .count()
+    fn get_ways_to_win(time: u64, record: u64) -> u64 {
+        crate::ways_to_win(time, record)
     }
 }
-fn parse_time(input: &str) -> IResult<&str, u64> {
+fn parse_time(input: &str) -> IResult<&str, Vec<u64>> {
     let (input, timings) = preceded(
         nom::bytes::complete::tag("Time:"),
         preceded(multispace0, separated_list1(multispace1, complete::u64)),
     )(input)?;
-    let timing = timings.iter()
-        .fold(String::new(), |mut acc,val| {acc.push_str(&val.to_string()); return acc;})
-        .parse::<u64>().unwrap();
-    Ok((input, timing))
+    Ok((input, timings))
 }
-fn parse_distance(input: &str) -> IResult<&str, u64> {
+fn parse_distance(input: &str) -> IResult<&str, Vec<u64>> {
     let (input, distances) = preceded(
         nom::bytes::complete::tag("Distance:"),
         preceded(multispace0, separated_list1(multispace1, complete::u64)),
     )(input)?;
-    let distance = distances.iter()
-        .fold(String::new(), |mut acc,val| {acc.push_str(&val.to_string()); return acc;})
-        .parse::<u64>().unwrap();
-    Ok((input, distance))
+    Ok((input, distances))
+}
+/// The numbers of `line`, a slice of `source`, read as one with the spaces between them removed.
+fn kerned(source: &str, line: &str, values: &[u64]) -> Result<u64, ParseError> {
+    values
+        .iter()
+        .fold(String::new(), |mut acc, val| {
+            acc.push_str(&val.to_string());
+            acc
+        })
+        .parse::<u64>()
+        .map_err(|_| ParseError::at(source, line, "numbers that read as one within u64"))
 }
 
 impl FromStr for RecordTable {
-    type Err = std::io::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_, (timing, distance)) =
-            separated_pair(parse_time, newline, parse_distance)(s).unwrap();
-        Ok(RecordTable { timing, distance })
+        let (timings, distances) = crate::parse_sheet(s, parse_time, parse_distance)?;
+        let mut lines = s.lines();
+        Ok(RecordTable {
+            timing: kerned(s, lines.next().unwrap_or_default(), &timings)?,
+            distance: kerned(s, lines.next().unwrap_or_default(), &distances)?,
+        })
+    }
+}
+
+impl fmt::Display for RecordTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Time: {}\nDistance: {}", self.timing, self.distance)
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::RecordTable;
+    use super::RecordTable;
 
     #[test]
     fn load_input() {
@@ -76,4 +83,20 @@ Distance:  9  40  200";
             71503
         );
     }
+    #[test]
+    fn reject_malformed_sheets() {
+        let err = "Time: 7 15\nDistance: 9"
+            .parse::<RecordTable>()
+            .unwrap_err();
+        assert_eq!(err.expected, "2 distances, one per time");
+        let err = "Time: 7 15\nDistance: 9 40\nmore"
+            .parse::<RecordTable>()
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        let err = "Time: 1 18446744073709551615\nDistance: 9 40"
+            .parse::<RecordTable>()
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.expected, "numbers that read as one within u64");
+    }
 }
@@ -0,0 +1,14 @@
+use common::{ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day7;
+impl Solution for Day7 {
+    fn part1(&self, input: &str) -> Result<String, ParseError> {
+        Ok(part1::winnings(input)?.to_string())
+    }
+    fn part2(&self, input: &str) -> Result<String, ParseError> {
+        Ok(part2::winnings(input)?.to_string())
+    }
+}
@@ -0,0 +1,161 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use common::ParseError;
+use indexmap::IndexMap;
+use nom::{
+    character::complete::{self, one_of, space1},
+    combinator::all_consuming,
+    multi::count,
+    sequence::separated_pair,
+    IResult,
+};
+
+const CARD_ORDER: &str = "J23456789TQKA";
+const JOKER: char = 'J';
+
+pub fn winnings(input: &str) -> Result<u64, ParseError> {
+    let hands: Vec<Hand> = input
+        .lines()
+        .zip(1..)
+        .map(|(line, number)| line.parse::<Hand>().map_err(|err| err.in_line(number)))
+        .collect::<Result<_, _>>()?;
+    Ok(total_winnings(hands))
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+impl HandType {
+    fn from_cards(cards: &[char]) -> Self {
+        let mut labels: IndexMap<char, u32> = IndexMap::new();
+        cards
+            .iter()
+            .filter(|card| **card != JOKER)
+            .for_each(|card| *labels.entry(*card).or_insert(0) += 1);
+        let jokers = cards.iter().filter(|card| **card == JOKER).count() as u32;
+        let mut counts: Vec<u32> = labels.values().copied().collect();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        //jokers always join the largest group, a hand of only jokers is five of a kind
+        match counts.first_mut() {
+            Some(largest) => *largest += jokers,
+            None => counts.push(jokers),
+        }
+        match counts.as_slice() {
+            [5] => HandType::FiveOfAKind,
+            [4, ..] => HandType::FourOfAKind,
+            [3, 2] => HandType::FullHouse,
+            [3, ..] => HandType::ThreeOfAKind,
+            [2, 2, ..] => HandType::TwoPair,
+            [2, ..] => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Hand {
+    cards: Vec<char>,
+    bid: u32,
+    hand_type: HandType,
+}
+impl Hand {
+    fn card_strength(card: char) -> usize {
+        CARD_ORDER.find(card).expect("Card label should be valid")
+    }
+}
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.hand_type.cmp(&other.hand_type).then_with(|| {
+            self.cards
+                .iter()
+                .map(|card| Hand::card_strength(*card))
+                .cmp(other.cards.iter().map(|card| Hand::card_strength(*card)))
+        })
+    }
+}
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn parse_hand(input: &str) -> IResult<&str, (Vec<char>, u32)> {
+    let (input, (cards, bid)) =
+        separated_pair(count(one_of(CARD_ORDER), 5), space1, complete::u32)(input)?;
+    Ok((input, (cards, bid)))
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, (cards, bid)) = all_consuming(parse_hand)(s.trim())
+            .map_err(|err| ParseError::nom(s, err, "five card labels and a bid"))?;
+        let hand_type = HandType::from_cards(&cards);
+        Ok(Hand {
+            cards,
+            bid,
+            hand_type,
+        })
+    }
+}
+
+fn total_winnings(mut hands: Vec<Hand>) -> u64 {
+    hands.sort();
+    hands
+        .iter()
+        .zip(1..)
+        .fold(0, |acc, (hand, rank)| acc + hand.bid as u64 * rank)
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::{total_winnings, Hand, HandType};
+
+    #[rstest]
+    #[case("32T3K 765", HandType::OnePair)]
+    #[case("T55J5 684", HandType::FourOfAKind)]
+    #[case("KK677 28", HandType::TwoPair)]
+    #[case("KTJJT 220", HandType::FourOfAKind)]
+    #[case("QQQJA 483", HandType::FourOfAKind)]
+    #[case("JJJJJ 1", HandType::FiveOfAKind)]
+    #[case("JJJJ2 1", HandType::FiveOfAKind)]
+    #[case("2233J 1", HandType::FullHouse)]
+    #[case("2345J 1", HandType::OnePair)]
+    fn classify_hand(#[case] input: &str, #[case] expected: HandType) {
+        let hand = input.parse::<Hand>().unwrap();
+        assert_eq!(hand.hand_type, expected);
+    }
+    #[rstest]
+    #[case("QQQQ2 1", "JKKK2 1")]
+    #[case("KTJJT 1", "QQQJA 1")]
+    #[case("22222 1", "JJJJJ 1")]
+    #[case("2345J 1", "J3456 1")]
+    fn compare_hands(#[case] stronger: &str, #[case] weaker: &str) {
+        let stronger = stronger.parse::<Hand>().unwrap();
+        let weaker = weaker.parse::<Hand>().unwrap();
+        assert!(stronger > weaker);
+    }
+    #[test]
+    fn solve_an_example() {
+        let input = r"32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+        let hands: Vec<Hand> = input
+            .lines()
+            .map(|line| line.parse::<Hand>().unwrap())
+            .collect();
+        assert_eq!(total_winnings(hands), 5905);
+    }
+}
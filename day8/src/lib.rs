@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+
+use common::{ParseError, Solution};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alphanumeric1, line_ending, multispace0, multispace1, one_of},
+    combinator::all_consuming,
+    multi::{many1, separated_list1},
+    sequence::{delimited, separated_pair},
+    IResult,
+};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Direction {
+    Left,
+    Right,
+}
+
+struct Network<'a> {
+    source: &'a str,
+    instructions: Vec<Direction>,
+    nodes: HashMap<&'a str, (&'a str, &'a str)>,
+}
+
+fn parse_instructions(input: &str) -> IResult<&str, Vec<Direction>> {
+    let (input, directions) = many1(one_of("LR"))(input)?;
+    let instructions = directions
+        .iter()
+        .map(|direction| match direction {
+            'L' => Direction::Left,
+            _ => Direction::Right,
+        })
+        .collect();
+    Ok((input, instructions))
+}
+fn parse_node(input: &str) -> IResult<&str, (&str, (&str, &str))> {
+    let (input, (name, neighbours)) = separated_pair(
+        alphanumeric1,
+        tag(" = "),
+        delimited(
+            tag("("),
+            separated_pair(alphanumeric1, tag(", "), alphanumeric1),
+            tag(")"),
+        ),
+    )(input)?;
+    Ok((input, (name, neighbours)))
+}
+/// Reads the instructions and node table of a whole puzzle input, which the network keeps to
+/// point errors at.
+fn parse_network(source: &str) -> IResult<&str, Network<'_>> {
+    let (input, (instructions, nodes)) = separated_pair(
+        parse_instructions,
+        multispace1,
+        separated_list1(line_ending, parse_node),
+    )(source)?;
+    Ok((
+        input,
+        Network {
+            source,
+            instructions,
+            nodes: nodes.into_iter().collect(),
+        },
+    ))
+}
+
+impl<'a> Network<'a> {
+    pub fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let (rest, network) = parse_network(input)
+            .map_err(|err| ParseError::nom(input, err, "L/R instructions and a node table"))?;
+        all_consuming(multispace0)(rest)
+            .map_err(|err| ParseError::nom(input, err, "<node> = (<left>, <right>)"))?;
+        //the first neighbour, in input order, missing from the table
+        let undefined = network
+            .nodes
+            .values()
+            .flat_map(|&(left, right)| [left, right])
+            .filter(|name| !network.nodes.contains_key(name))
+            .min_by_key(|name| name.as_ptr());
+        match undefined {
+            Some(name) => Err(ParseError::at(input, name, "a node defined in the table")),
+            None => Ok(network),
+        }
+    }
+    /// Steps from `start` to the first end node, failing when the walk can never reach one.
+    fn steps<F>(&self, start: &'a str, is_end: F) -> Result<u64, ParseError>
+    where
+        F: Fn(&str) -> bool,
+    {
+        //past this many steps some node was left twice at the same instruction, so it loops
+        let limit = self.nodes.len() * self.instructions.len();
+        let mut current = start;
+        for (steps, direction) in self.instructions.iter().cycle().take(limit).enumerate() {
+            if is_end(current) {
+                return Ok(steps as u64);
+            }
+            let (left, right) = self.nodes[current];
+            current = match direction {
+                Direction::Left => left,
+                Direction::Right => right,
+            };
+        }
+        if is_end(current) {
+            return Ok(limit as u64);
+        }
+        Err(ParseError::at(
+            self.source,
+            start,
+            "a walk reaching an end node",
+        ))
+    }
+    fn walker_steps(&self) -> Result<u64, ParseError> {
+        match self.nodes.get_key_value("AAA") {
+            Some((start, _)) => self.steps(start, |node| node == "ZZZ"),
+            None => {
+                let end = &self.source[self.source.len()..];
+                Err(ParseError::at(self.source, end, "a node named AAA"))
+            }
+        }
+    }
+    fn ghost_steps(&self) -> Result<u64, ParseError> {
+        //in input order, so that errors point at the first ghost at fault
+        let mut starts: Vec<&str> = self
+            .nodes
+            .keys()
+            .copied()
+            .filter(|node| node.ends_with('A'))
+            .collect();
+        starts.sort_by_key(|start| start.as_ptr());
+        if starts.is_empty() {
+            let end = &self.source[self.source.len()..];
+            return Err(ParseError::at(self.source, end, "a node ending in A"));
+        }
+        //every ghost loops through its own end node, so they all meet at the lcm of their cycles
+        starts.into_iter().try_fold(1, |acc, start| {
+            let steps = self.steps(start, |node| node.ends_with('Z'))?;
+            lcm(acc, steps).ok_or_else(|| {
+                ParseError::at(self.source, start, "ghost cycles meeting within u64")
+            })
+        })
+    }
+}
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        return a;
+    }
+    gcd(b, a % b)
+}
+/// The least common multiple of `a` and `b`, `None` when it does not fit in u64.
+fn lcm(a: u64, b: u64) -> Option<u64> {
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+pub struct Day8;
+impl Solution for Day8 {
+    fn part1(&self, input: &str) -> Result<String, ParseError> {
+        Ok(Network::parse(input)?.walker_steps()?.to_string())
+    }
+    fn part2(&self, input: &str) -> Result<String, ParseError> {
+        Ok(Network::parse(input)?.ghost_steps()?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{lcm, parse_instructions, parse_node, Direction, Network};
+
+    #[test]
+    fn parse_directions() {
+        let (_, instructions) = parse_instructions("LLR").unwrap();
+        assert_eq!(
+            instructions,
+            vec![Direction::Left, Direction::Left, Direction::Right]
+        );
+    }
+    #[test]
+    fn parse_single_node() {
+        let (_, node) = parse_node("AAA = (BBB, CCC)").unwrap();
+        assert_eq!(node, ("AAA", ("BBB", "CCC")));
+    }
+    #[test]
+    fn test_example() {
+        let input = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+        let network = Network::parse(input).unwrap();
+        assert_eq!(network.nodes.len(), 7);
+        assert_eq!(network.walker_steps(), Ok(2));
+    }
+    #[test]
+    fn test_example_repeating() {
+        let input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        let network = Network::parse(input).unwrap();
+        assert_eq!(network.walker_steps(), Ok(6));
+    }
+    #[test]
+    fn test_example_ghosts() {
+        let input = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+        let network = Network::parse(input).unwrap();
+        assert_eq!(network.ghost_steps(), Ok(6));
+    }
+    #[test]
+    fn report_malformed_instructions() {
+        let err = Network::parse("\n\nAAA = (BBB, CCC)").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+    #[test]
+    fn report_missing_ghosts() {
+        let network = Network::parse("LR\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        let err = network.ghost_steps().unwrap_err();
+        assert_eq!((err.line, err.column), (4, 17));
+        assert_eq!(err.expected, "a node ending in A");
+    }
+    #[test]
+    fn report_trailing_lines() {
+        let input = "LR\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+        assert_eq!(
+            Network::parse(input).map(|network| network.nodes.len()),
+            Ok(2)
+        );
+        let err = Network::parse(&format!("{}BBB = CCC\n", input))
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (5, 1));
+        assert_eq!(err.expected, "<node> = (<left>, <right>)");
+    }
+    #[test]
+    fn report_undefined_nodes() {
+        let err = Network::parse("LR\n\nAAA = (BBB, ZZZ)\nBBB = (CCC, AAA)\nZZZ = (ZZZ, ZZZ)")
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (4, 8));
+        assert_eq!(err.expected, "a node defined in the table");
+    }
+    #[test]
+    fn report_unreachable_ends() {
+        let network = Network::parse("LR\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        let err = network.walker_steps().unwrap_err();
+        assert_eq!(err.expected, "a node named AAA");
+        let network =
+            Network::parse("LR\n\nAAA = (BBB, AAA)\nBBB = (AAA, BBB)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        let err = network.walker_steps().unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.expected, "a walk reaching an end node");
+        let err = network.ghost_steps().unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(1, 7), Some(7));
+        assert_eq!(lcm(u64::MAX, u64::MAX), Some(u64::MAX));
+        assert_eq!(lcm(u64::MAX, 2), None);
+    }
+}
@@ -0,0 +1,213 @@
+use std::io::BufRead;
+
+use common::{
+    input::{parse_lines, read_lines},
+    InputError, ParseError, Solution,
+};
+
+mod polynomial;
+
+pub use polynomial::Polynomial;
+
+/// Predicts values of polynomial histories from the differences of their first value.
+///
+/// A history is loaded by computing its difference rows in place, one buffer reused from
+/// one history to the next, until a row is all zeros. Values are then read off Newton's
+/// forward formula, so predicting far from the history costs no more than predicting next.
+#[derive(Debug, Default)]
+pub struct Extrapolator {
+    //Δ^k of the first value for every k up to the degree, once fitted
+    differences: Vec<i128>,
+}
+impl Extrapolator {
+    /// Loads `history`, `None` when its differences overflow.
+    pub fn fit(&mut self, history: &[i64]) -> Option<&mut Self> {
+        let row = &mut self.differences;
+        row.clear();
+        row.extend(history.iter().map(|value| *value as i128));
+        for level in 1..=row.len() {
+            //row[level - 1..] holds the differences of order level - 1
+            if row[level - 1..].iter().all(|difference| *difference == 0) {
+                row.truncate(level - 1);
+                break;
+            }
+            for idx in (level..row.len()).rev() {
+                row[idx] = row[idx].checked_sub(row[idx - 1])?;
+            }
+        }
+        Some(self)
+    }
+    /// Δ^k of the first value of the fitted history, ending with the last non zero one.
+    pub fn differences(&self) -> &[i128] {
+        &self.differences
+    }
+    /// The value at `index` of the fitted history, the first value being at index 0.
+    pub fn value_at(&self, index: i128) -> Option<i64> {
+        let mut value: i128 = 0;
+        //binomial coefficient (index choose k)
+        let mut choose: i128 = 1;
+        for (k, difference) in (0..).zip(&self.differences) {
+            value = value.checked_add(choose.checked_mul(*difference)?)?;
+            choose = choose.checked_mul(index.checked_sub(k)?)? / (k + 1);
+        }
+        i64::try_from(value).ok()
+    }
+    /// The value `steps` after the last one of `history`, `None` on overflow.
+    pub fn forward(&mut self, history: &[i64], steps: u64) -> Option<i64> {
+        let last = history.len() as i128 - 1;
+        self.fit(history)?.value_at(last + steps as i128)
+    }
+    /// The value `steps` before the first one of `history`, `None` on overflow.
+    pub fn backward(&mut self, history: &[i64], steps: u64) -> Option<i64> {
+        self.fit(history)?.value_at(-(steps as i128))
+    }
+}
+
+fn parse_history(line: &str, history: &mut Vec<i64>) -> Result<(), ParseError> {
+    history.clear();
+    if line.trim().is_empty() {
+        return Err(ParseError::at(line, line, "a history of integers"));
+    }
+    for num_str in line.split_whitespace() {
+        history.push(
+            num_str
+                .parse::<i64>()
+                .map_err(|_| ParseError::at(line, num_str, "an integer"))?,
+        );
+    }
+    Ok(())
+}
+/// Every value of `input` that is not an integer, and every line without any value.
+pub fn validate(input: &str) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    for (line, number) in input.lines().zip(1..) {
+        if line.trim().is_empty() {
+            problems.push(ParseError::at(line, line, "a history of integers").in_line(number));
+        }
+        let malformed = line
+            .split_whitespace()
+            .filter(|value| value.parse::<i64>().is_err())
+            .map(|value| ParseError::at(line, value, "an integer").in_line(number));
+        problems.extend(malformed);
+    }
+    problems
+}
+/// Parses history lines into predictions, `backward` selecting the value before the first one.
+fn predictor(backward: bool) -> impl FnMut(&str) -> Result<i64, ParseError> {
+    let mut history = Vec::new();
+    let mut extrapolator = Extrapolator::default();
+    move |line| {
+        parse_history(line, &mut history)?;
+        let prediction = if backward {
+            extrapolator.backward(&history, 1)
+        } else {
+            extrapolator.forward(&history, 1)
+        };
+        prediction.ok_or_else(|| ParseError::at(line, line, "a history whose prediction fits in i64"))
+    }
+}
+fn sum_predictions<E>(mut predictions: impl Iterator<Item = Result<i64, E>>) -> Result<i128, E> {
+    predictions.try_fold(0, |acc, prediction| Ok(acc + prediction? as i128))
+}
+fn calculate_prediction(input: &str) -> Result<i128, ParseError> {
+    sum_predictions(parse_lines(input, predictor(false)))
+}
+fn calculate_prediction2(input: &str) -> Result<i128, ParseError> {
+    sum_predictions(parse_lines(input, predictor(true)))
+}
+
+pub struct Day9;
+impl Solution for Day9 {
+    fn part1(&self, input: &str) -> Result<String, ParseError> {
+        Ok(calculate_prediction(input)?.to_string())
+    }
+    fn part2(&self, input: &str) -> Result<String, ParseError> {
+        Ok(calculate_prediction2(input)?.to_string())
+    }
+    fn part1_from_reader(&self, input: &mut dyn BufRead) -> Result<String, InputError> {
+        Ok(sum_predictions(read_lines(input, predictor(false)))?.to_string())
+    }
+    fn part2_from_reader(&self, input: &mut dyn BufRead) -> Result<String, InputError> {
+        Ok(sum_predictions(read_lines(input, predictor(true)))?.to_string())
+    }
+    fn validate(&self, input: &str) -> Option<Vec<ParseError>> {
+        Some(validate(input))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{calculate_prediction, calculate_prediction2, validate, Day9, Extrapolator};
+    use common::Solution;
+
+    #[test]
+    fn test_example () {
+        let input = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+        let answer = calculate_prediction(input).unwrap();
+        assert_eq!(114, answer);
+    }
+    #[test]
+    fn test_example2 () {
+        let input = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+        let answer = calculate_prediction2(input).unwrap();
+        assert_eq!(2, answer);
+    }
+    #[test]
+    fn test_malformed_history () {
+        let err = calculate_prediction("0 3 6\n1 3 x6 10").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+        assert_eq!(err.snippet, "x6 10");
+    }
+    #[test]
+    fn validate_every_value () {
+        let positions: Vec<(usize, usize)> = validate("0 3 6\n1 3 x6 10 1.5\n\n10 13")
+            .into_iter()
+            .map(|err| (err.line, err.column))
+            .collect();
+        assert_eq!(positions, vec![(2, 5), (2, 11), (3, 1)]);
+        //whatever validate flags, the solvers refuse too
+        let err = calculate_prediction("0 3 6\n\n10 13").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (2, "a history of integers"));
+    }
+    #[test]
+    fn stream_histories () {
+        let input = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+        assert_eq!(Day9.part1_from_reader(&mut input.as_bytes()).unwrap(), "114");
+        assert_eq!(Day9.part2_from_reader(&mut input.as_bytes()).unwrap(), "2");
+    }
+    #[test]
+    fn zero_sum_is_not_zero_differences () {
+        let mut extrapolator = Extrapolator::default();
+        assert_eq!(extrapolator.forward(&[1, -1], 1), Some(-3));
+        assert_eq!(extrapolator.backward(&[1, -1], 1), Some(3));
+        assert_eq!(extrapolator.forward(&[3, -1, -2], 1), Some(0));
+        assert_eq!(extrapolator.fit(&[5, 5, 5]).unwrap().differences(), [5]);
+        assert_eq!(extrapolator.fit(&[0, 0]).unwrap().differences(), []);
+        assert_eq!(extrapolator.forward(&[], 1), Some(0));
+    }
+    #[test]
+    fn predict_many_steps () {
+        let mut extrapolator = Extrapolator::default();
+        let history = [10, 13, 16, 21, 30, 45];
+        assert_eq!(extrapolator.fit(&history).unwrap().differences(), [10, 3, 0, 2]);
+        assert_eq!(extrapolator.forward(&history, 1), Some(68));
+        assert_eq!(extrapolator.forward(&history, 2), Some(101));
+        assert_eq!(extrapolator.backward(&history, 1), Some(5));
+        assert_eq!(extrapolator.backward(&history, 2), Some(-4));
+        let squares = [0, 1, 4, 9];
+        assert_eq!(extrapolator.forward(&squares, 1_000_000), Some(1_000_003i64.pow(2)));
+        assert_eq!(extrapolator.backward(&squares, 7), Some(49));
+    }
+    #[test]
+    fn report_overflow () {
+        let mut extrapolator = Extrapolator::default();
+        assert_eq!(extrapolator.forward(&[0, i64::MAX], 1), None);
+        assert_eq!(extrapolator.forward(&[i64::MIN, i64::MAX], 0), Some(i64::MAX));
+        let err = calculate_prediction("0 3 6\n0 9223372036854775807").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
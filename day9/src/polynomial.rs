@@ -0,0 +1,152 @@
+use std::fmt;
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+
+/// A polynomial with exact rational coefficients, lowest degree first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    coefficients: Vec<BigRational>,
+}
+
+impl Polynomial {
+    /// The polynomial of lowest degree taking the value `history[i]` at `x = i`.
+    ///
+    /// Built from Newton's forward differences: with `Δ^k` the differences of the first
+    /// value, `p(x) = Σ Δ^k x(x-1)...(x-k+1) / k!`. Differences stop at the first all zero row.
+    pub fn fit(history: &[i64]) -> Self {
+        let mut row: Vec<BigInt> = history.iter().map(|value| BigInt::from(*value)).collect();
+        let mut coefficients = Vec::new();
+        //x(x-1)...(x-k+1), lowest degree first
+        let mut falling = vec![BigInt::one()];
+        let mut factorial = BigInt::one();
+        for k in 0..history.len() {
+            if row.iter().all(Zero::is_zero) {
+                break;
+            }
+            let scale = BigRational::new(row[0].clone(), factorial.clone());
+            coefficients.resize(falling.len(), BigRational::zero());
+            for (coefficient, term) in coefficients.iter_mut().zip(&falling) {
+                *coefficient += &scale * BigRational::from_integer(term.clone());
+            }
+            //multiply by (x - k) for the next term
+            let k = BigInt::from(k);
+            let mut next = vec![BigInt::zero(); falling.len() + 1];
+            for (degree, term) in falling.iter().enumerate() {
+                next[degree + 1] += term;
+                next[degree] -= term * &k;
+            }
+            falling = next;
+            factorial *= &k + 1;
+            row = row.windows(2).map(|pair| &pair[1] - &pair[0]).collect();
+        }
+        let mut polynomial = Polynomial { coefficients };
+        polynomial.trim();
+        polynomial
+    }
+    /// Coefficients from the constant term up, empty for the zero polynomial.
+    pub fn coefficients(&self) -> &[BigRational] {
+        &self.coefficients
+    }
+    /// `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+    pub fn eval(&self, x: &BigRational) -> BigRational {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(BigRational::zero(), |acc, coefficient| acc * x + coefficient)
+    }
+    /// The value at an integer index, the first value of the history being at index 0.
+    ///
+    /// Polynomials fitted on integer histories take integer values at every integer index.
+    pub fn value_at(&self, index: impl Into<BigInt>) -> BigInt {
+        let value = self.eval(&BigRational::from_integer(index.into()));
+        debug_assert!(value.is_integer());
+        value.to_integer()
+    }
+    fn trim(&mut self) {
+        while self.coefficients.last().is_some_and(Zero::is_zero) {
+            self.coefficients.pop();
+        }
+    }
+}
+
+/// Highest degree first, such as `3/2x^2 - x + 7`.
+impl fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.coefficients.is_empty() {
+            return write!(f, "0");
+        }
+        let terms = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, coefficient)| !coefficient.is_zero());
+        for (idx, (degree, coefficient)) in terms.enumerate() {
+            match (idx, coefficient.is_negative()) {
+                (0, true) => write!(f, "-")?,
+                (0, false) => (),
+                (_, true) => write!(f, " - ")?,
+                (_, false) => write!(f, " + ")?,
+            }
+            let magnitude = coefficient.abs();
+            if degree == 0 || !magnitude.is_one() {
+                write!(f, "{}", magnitude)?;
+            }
+            match degree {
+                0 => (),
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{}", degree)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigInt;
+    use num_rational::BigRational;
+
+    use super::Polynomial;
+    use crate::Extrapolator;
+
+    #[test]
+    fn fit_the_examples() {
+        let polynomial = Polynomial::fit(&[10, 13, 16, 21, 30, 45]);
+        assert_eq!(polynomial.degree(), Some(3));
+        assert_eq!(polynomial.to_string(), "1/3x^3 - x^2 + 11/3x + 10");
+        assert_eq!(polynomial.value_at(6), BigInt::from(68));
+        assert_eq!(polynomial.value_at(-1), BigInt::from(5));
+        let line = Polynomial::fit(&[0, 3, 6, 9, 12, 15]);
+        assert_eq!(line.to_string(), "3x");
+        assert_eq!(line.coefficients()[1], BigRational::from_integer(3.into()));
+        assert_eq!(Polynomial::fit(&[0, 0, 0]).degree(), None);
+        assert_eq!(Polynomial::fit(&[0, 0, 0]).to_string(), "0");
+        assert_eq!(Polynomial::fit(&[4]).to_string(), "4");
+        assert_eq!(Polynomial::fit(&[1, -1]).to_string(), "-2x + 1");
+    }
+    #[test]
+    fn evaluate_far_away() {
+        let cubes = Polynomial::fit(&[0, 1, 8, 27]);
+        let far = BigInt::from(10).pow(12);
+        assert_eq!(cubes.value_at(far.clone()), far.pow(3));
+        assert_eq!(cubes.value_at(-3), BigInt::from(-27));
+    }
+    #[test]
+    fn agree_with_the_extrapolator() {
+        let history = [3, -4, 19, 120, 399, 1010, -7];
+        let polynomial = Polynomial::fit(&history);
+        let mut extrapolator = Extrapolator::default();
+        for steps in 0..20 {
+            let expected = extrapolator.forward(&history, steps).unwrap();
+            assert_eq!(polynomial.value_at(6 + steps), BigInt::from(expected));
+            let expected = extrapolator.backward(&history, steps).unwrap();
+            assert_eq!(polynomial.value_at(-(steps as i64)), BigInt::from(expected));
+        }
+    }
+}